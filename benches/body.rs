@@ -0,0 +1,78 @@
+//! Compares iterating over [Snake::body_view] against collecting [Snake::body] into a [Vec]
+//! first, as renderers do every frame.
+//!
+//! Run with `cargo bench --bench body`.
+
+use rust_snake::map::Map;
+use rust_snake::snake::{Direction, Snake};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// The width and height of the map.
+const SIZE: usize = 100;
+/// The number of times the body is iterated.
+const FRAMES: usize = 1000;
+
+/// Moves a snake back and forth across the map, until it fills the map.
+fn long_snake(map: &mut Map<SIZE, SIZE>) -> Snake {
+    let mut snake = Snake::new(0, 0, SIZE * SIZE);
+    snake.place_head(map);
+
+    for y in 0..SIZE {
+        let dir = if y % 2 == 0 {
+            Direction::Right
+        } else {
+            Direction::Left
+        };
+        for x in 0..SIZE {
+            snake.turn(if x == 0 && y != 0 {
+                Direction::Down
+            } else {
+                dir
+            });
+            if x == 0 && y == 0 {
+                continue;
+            }
+            snake.forward();
+            snake.cut_tail(map);
+            snake.place_head(map);
+        }
+    }
+
+    snake
+}
+
+fn main() {
+    let mut map = Map::<SIZE, SIZE>::new();
+    let snake = long_snake(&mut map);
+
+    // Marks the locations of the snake in a frame buffer, like a renderer would.
+    let mut frame = vec![0; SIZE * SIZE];
+
+    let start = Instant::now();
+    for i in 0..FRAMES {
+        for (x, y) in black_box(&snake).body_view() {
+            frame[x * SIZE + y] = i;
+        }
+    }
+    let view = start.elapsed();
+    let view_frame = black_box(frame.clone());
+
+    let start = Instant::now();
+    for i in 0..FRAMES {
+        let body: Vec<_> = black_box(&snake).body().collect();
+        for (x, y) in body {
+            frame[x * SIZE + y] = i;
+        }
+    }
+    let collected = start.elapsed();
+
+    assert_eq!(view_frame, frame);
+    println!(
+        "snake of size {}, {} frames",
+        snake.body_view().len(),
+        FRAMES
+    );
+    println!("Snake::body_view:     {:?}", view);
+    println!("Snake::body into Vec: {:?}", collected);
+}
@@ -0,0 +1,74 @@
+//! Compares [Snake::occupies] against naively scanning the snake's locations.
+//!
+//! Run with `cargo bench --bench occupancy`.
+
+use rust_snake::map::Map;
+use rust_snake::snake::{Direction, Snake};
+use std::hint::black_box;
+use std::time::Instant;
+
+/// The width and height of the map.
+const SIZE: usize = 100;
+/// The size of the snake, filling half of the map.
+const SNAKE_SIZE: usize = SIZE * SIZE / 2;
+
+/// Moves a snake back and forth across the map, until it reached its full size.
+///
+/// Returns the snake and its locations, ordered from oldest to newest.
+fn long_snake(map: &mut Map<SIZE, SIZE>) -> (Snake, Vec<(usize, usize)>) {
+    let mut snake = Snake::new(0, 0, SNAKE_SIZE);
+    let mut locations = vec![(0, 0)];
+    snake.place_head(map);
+
+    for y in 0..SIZE / 2 {
+        let dir = if y % 2 == 0 {
+            Direction::Right
+        } else {
+            Direction::Left
+        };
+        for x in 0..SIZE {
+            snake.turn(if x == 0 && y != 0 {
+                Direction::Down
+            } else {
+                dir
+            });
+            if x == 0 && y == 0 {
+                continue;
+            }
+            snake.forward();
+            snake.cut_tail(map);
+            snake.place_head(map);
+            locations.push((snake.x() as usize, snake.y() as usize));
+        }
+    }
+
+    (snake, locations)
+}
+
+fn main() {
+    let mut map = Map::<SIZE, SIZE>::new();
+    let (snake, locations) = long_snake(&mut map);
+
+    let start = Instant::now();
+    let mut hits = 0;
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            hits += black_box(snake.occupies(x, y)) as usize;
+        }
+    }
+    let occupies = start.elapsed();
+
+    let start = Instant::now();
+    let mut naive_hits = 0;
+    for x in 0..SIZE {
+        for y in 0..SIZE {
+            naive_hits += black_box(locations.contains(&(x, y))) as usize;
+        }
+    }
+    let naive = start.elapsed();
+
+    assert_eq!(hits, naive_hits);
+    println!("snake of size {}, {} lookups", locations.len(), SIZE * SIZE);
+    println!("Snake::occupies: {:?}", occupies);
+    println!("naive scan:      {:?}", naive);
+}
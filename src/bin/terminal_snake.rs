@@ -1,11 +1,13 @@
 extern crate rust_snake;
 
-use crossterm::style::{Color, Stylize};
-use crossterm::{cursor, event, execute, style, ExecutableCommand, QueueableCommand};
-use rust_snake::{map, snake, Game, State};
-use std::io::{stdout, Write};
+use crossterm::style::Color;
+use crossterm::{cursor, event, execute, style, ExecutableCommand};
+use rust_snake::terminal::{self, Layout, ParseThemeError, Theme};
+use rust_snake::{snake, Game};
+use std::io::stdout;
+use std::process::ExitCode;
 use std::sync::mpsc;
-use std::{io, thread, time};
+use std::{thread, time};
 
 /// The width of the map.
 const MAP_WIDTH: usize = 30;
@@ -14,10 +16,25 @@ const MAP_HEIGHT: usize = 30;
 /// The time delay between every snake move, in milliseconds.
 const DELAY: usize = 100;
 
+/// The environment variable selecting the [Theme], if no `--theme` argument is given.
+const THEME_VAR: &str = "SNAKE_THEME";
+
 /// The main Function.
 ///
 /// Starts a new game of snake in the terminal and terminates when the game ends.
-pub fn main() {
+///
+/// The colors are picked through `--theme <name>` or the `SNAKE_THEME` environment variable,
+/// with `classic`, `mono` and `neon` available, see [Theme].
+pub fn main() -> ExitCode {
+    let theme = match theme() {
+        Ok(theme) => theme,
+        Err(err) => {
+            let names: Vec<_> = Theme::all().iter().map(Theme::to_string).collect();
+            eprintln!("{err}, expected one of: {}", names.join(", "));
+            return ExitCode::FAILURE;
+        }
+    };
+
     // Used to transfer directions from the input thread to the main thread.
     let (sender, receiver) = mpsc::channel();
 
@@ -29,32 +46,38 @@ pub fn main() {
 
     // Initialize the game.
     let mut game = Game::<MAP_WIDTH, MAP_HEIGHT>::new();
+    let style = theme.style();
+    let layout = Layout {
+        show_score: true,
+        ..Layout::default()
+    };
+    let lines = layout.lines(MAP_HEIGHT) as u16;
 
     execute!(stdout(), cursor::Hide).unwrap();
-    for _ in 0..MAP_HEIGHT + 1 {
-        write!(stdout(), "\n").unwrap();
+    for _ in 0..lines - 1 {
+        println!();
     }
-    execute!(stdout(), cursor::MoveToPreviousLine(MAP_HEIGHT as u16 + 2)).unwrap();
+    execute!(stdout(), cursor::MoveToPreviousLine(lines)).unwrap();
 
     // Draw the map and panic if the draw function returns an error.
-    game.display(|map| draw(map).unwrap());
+    terminal::draw(&mut stdout(), &game, &style, &layout).unwrap();
 
     // The game loop.
-    while game.state != State::GameOver {
+    while !game.is_over() {
         if let Some(dir) = receiver.try_iter().last() {
             match dir {
                 // End the game if the user inputs a backspace
                 snake::Direction::None => game.game_over(),
                 _ => {
                     // Turn the snake to the last inputted direction and move it forward.
-                    game.state = State::Running;
+                    game.set_paused(false);
                     game.turn_snake(dir);
                 }
             }
         }
 
         // Check if the game isn't paused
-        if game.state == State::Running {
+        if game.is_running() {
             game.move_snake();
 
             // Clear the last outputted map.
@@ -64,11 +87,11 @@ pub fn main() {
                 ))
                 .unwrap(); // Panic if unable to clear the map
             stdout()
-                .execute(cursor::MoveToPreviousLine(MAP_HEIGHT as u16 + 1))
+                .execute(cursor::MoveToPreviousLine(lines - 1))
                 .unwrap();
 
             // Draw the map.
-            game.display(|map| draw(map)).unwrap();
+            terminal::draw(&mut stdout(), &game, &style, &layout).unwrap();
         }
 
         // Sleep before attempting to move the snake again.
@@ -88,7 +111,7 @@ pub fn main() {
 
     spin_sleep::sleep(time::Duration::from_secs(1));
 
-    write!(stdout(), "Press any Key to continue ... \r\n").unwrap();
+    print!("Press any Key to continue ... \r\n");
 
     // Drop the receiver, so the input thread terminates.
     drop(receiver);
@@ -102,6 +125,24 @@ pub fn main() {
     crossterm::terminal::disable_raw_mode().unwrap();
     execute!(stdout(), crossterm::terminal::LeaveAlternateScreen).unwrap();
     execute!(stdout(), cursor::Show).unwrap();
+
+    ExitCode::SUCCESS
+}
+
+/// Returns the [Theme] named by the `--theme` argument or the [THEME_VAR] environment variable,
+/// or the default one if neither is set.
+///
+/// Returns an [Err] describing the problem if the name is unknown or missing after `--theme`.
+fn theme() -> Result<Theme, String> {
+    let mut args = std::env::args().skip(1);
+    let name = match args.position(|arg| arg == "--theme") {
+        Some(_) => Some(args.next().ok_or("missing theme after --theme")?),
+        None => std::env::var(THEME_VAR).ok(),
+    };
+
+    name.map_or(Ok(Theme::default()), |name| {
+        name.parse().map_err(|err: ParseThemeError| err.to_string())
+    })
 }
 
 /// Create a thread continuously capturing user inputs from the terminal.
@@ -127,10 +168,16 @@ fn capture_inputs(
                     kind: event::KeyEventKind::Press,
                     ..
                 }) => match code {
-                    event::KeyCode::Left => Some(snake::Direction::Left),
-                    event::KeyCode::Right => Some(snake::Direction::Right),
-                    event::KeyCode::Up => Some(snake::Direction::Up),
-                    event::KeyCode::Down => Some(snake::Direction::Down),
+                    event::KeyCode::Left | event::KeyCode::Char('a') => {
+                        Some(snake::Direction::Left)
+                    }
+                    event::KeyCode::Right | event::KeyCode::Char('d') => {
+                        Some(snake::Direction::Right)
+                    }
+                    event::KeyCode::Up | event::KeyCode::Char('w') => Some(snake::Direction::Up),
+                    event::KeyCode::Down | event::KeyCode::Char('s') => {
+                        Some(snake::Direction::Down)
+                    }
                     event::KeyCode::Backspace => Some(snake::Direction::None),
                     _ => None,
                 },
@@ -152,46 +199,3 @@ fn capture_inputs(
         Ok(())
     })
 }
-
-/// Prints out the map in the terminal.
-///
-/// The Map will be encased by a border, made up of [BORDER_SYMBOL].
-///
-/// Returns an [Err] if a terminal operation fails.
-fn draw<const W: usize, const H: usize>(map: &map::Map<W, H>) -> io::Result<()> {
-    let border = "  ".on(Color::DarkGrey);
-    let snake = "  ".on(Color::Green);
-    let food = "  ".on(Color::Yellow);
-    let empty = "  ".on(Color::Black);
-
-    for _ in 0..W + 2 {
-        stdout().queue(style::PrintStyledContent(border)).unwrap();
-    }
-    stdout().queue(cursor::MoveToNextLine(1)).unwrap();
-    // Iterate over the map rows and print them.
-    for y in 0..MAP_HEIGHT {
-        stdout().queue(style::PrintStyledContent(border)).unwrap();
-
-        // Append each symbol with spaces in between.
-        for x in 0..MAP_WIDTH {
-            stdout()
-                .queue(style::PrintStyledContent(match map.get(x, y) {
-                    map::Tile::Empty => empty,
-                    map::Tile::Snake => snake,
-                    map::Tile::Food => food,
-                }))
-                .unwrap();
-        }
-
-        stdout().queue(style::PrintStyledContent(border)).unwrap();
-        stdout().queue(cursor::MoveToNextLine(1)).unwrap();
-    }
-
-    for _ in 0..W + 2 {
-        stdout().queue(style::PrintStyledContent(border)).unwrap();
-    }
-
-    stdout().flush().unwrap();
-
-    Ok(())
-}
@@ -0,0 +1,586 @@
+use crate::map::Map;
+use crate::{BorderConfig, Game, SpeedBasis, WallBehavior};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::fmt;
+
+/// Builder for a configured [Game], created through [Game::builder].
+///
+/// Every setting left out keeps the value [Game::new] uses.
+///
+/// # Examples
+/// ```
+/// use rust_snake::{builder::BuildError, map::Tile, Game, WallBehavior};
+/// let game = Game::<10, 10>::builder()
+///     .wall_behavior(WallBehavior::Wrap)
+///     .initial_size(5)
+///     .growth(2)
+///     .food_count(3)
+///     .seed(42)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(game.wall_behavior(), WallBehavior::Wrap);
+/// assert_eq!(game.display(|map| map.count(Tile::Food)), 3);
+///
+/// // Games with the same seed place their food identically.
+/// let other = Game::<10, 10>::builder().food_count(3).seed(42).build().unwrap();
+/// assert!(game.display(|map| other.display(|other| {
+///     map.locations(Tile::Food).eq(other.locations(Tile::Food))
+/// })));
+///
+/// assert_eq!(
+///     Game::<2, 2>::builder().initial_size(5).build().err(),
+///     Some(BuildError::InitialSize(5))
+/// );
+/// ```
+pub struct GameBuilder<const W: usize, const H: usize> {
+    game: Game<W, H>,
+    seed: Option<u64>,
+    /// The explicitly set initial size, validated when building.
+    initial_size: Option<usize>,
+    /// The explicitly set food count, validated when building.
+    food_count: Option<usize>,
+}
+
+impl<const W: usize, const H: usize> GameBuilder<W, H> {
+    /// Creates a builder with the default configuration.
+    pub fn new() -> Self {
+        GameBuilder {
+            game: Game::with_rng(StdRng::from_entropy()),
+            seed: None,
+            initial_size: None,
+            food_count: None,
+        }
+    }
+
+    /// Sets what happens when the snake tries to leave the map, see [Game::set_wall_behavior].
+    pub fn wall_behavior(mut self, behavior: WallBehavior) -> Self {
+        self.game.border = BorderConfig::uniform(behavior);
+        self
+    }
+
+    /// Sets what happens at each edge of the map, see [Game::set_border_config].
+    pub fn border_config(mut self, config: BorderConfig) -> Self {
+        self.game.border = config;
+        self
+    }
+
+    /// Sets the size of the snake after every (re)start.
+    pub fn initial_size(mut self, size: usize) -> Self {
+        self.initial_size = Some(size);
+        self
+    }
+
+    /// Sets by how much the snake grows for every food tile it eats.
+    pub fn growth(mut self, growth: usize) -> Self {
+        self.game.growth = growth;
+        self
+    }
+
+    /// Caps the size of the snake, so it stops growing once it reached the given size, while
+    /// eating still increases the score, see [Game::is_max_size].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).max_size(4).build().unwrap();
+    /// for y in 1..5 {
+    ///     game.set_tile(5, y, Tile::Food);
+    /// }
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert!(game.is_max_size());
+    /// assert_eq!(game.snake_body().count(), 4);
+    /// assert_eq!(game.score(), 4);
+    /// ```
+    pub fn max_size(mut self, size: usize) -> Self {
+        self.game.max_size = Some(size);
+        self
+    }
+
+    /// Gives the snake the given number of lives, one by default.
+    ///
+    /// When the snake dies while it has lives left, it respawns in the middle of the map with the
+    /// initial size instead of ending the game, keeping the score. The move it died on counts as
+    /// [died](crate::MoveOutcome::Died). If the middle is blocked, e.g. by a wall, the game ends
+    /// right away. Quitting or running out of time ends the game regardless of the lives left.
+    ///
+    /// Zero lives count as one.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).lives(3).build().unwrap();
+    /// game.set_tile(5, 3, Tile::Food);
+    /// assert_eq!(game.lives(), 3);
+    ///
+    /// // Leave the map twice and respawn.
+    /// for lives in [2, 1] {
+    ///     game.turn_snake(Direction::Up);
+    ///     for _ in 0..6 {
+    ///         game.move_snake();
+    ///     }
+    ///     assert!(!game.is_over());
+    ///     assert_eq!(game.lives(), lives);
+    ///     assert_eq!(game.snake_head(), (5, 5));
+    ///     assert_eq!(game.snake().size, 3);
+    ///     assert_eq!(game.score(), 1);
+    /// }
+    ///
+    /// // The third death ends the game.
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..6 {
+    ///     game.move_snake();
+    /// }
+    /// assert!(game.is_over());
+    /// assert_eq!(game.lives(), 0);
+    /// ```
+    pub fn lives(mut self, lives: u32) -> Self {
+        self.game.max_lives = lives.max(1);
+        self
+    }
+
+    /// Sets the number of food tiles on the map at the same time.
+    pub fn food_count(mut self, count: usize) -> Self {
+        self.food_count = Some(count);
+        self
+    }
+
+    /// Sets the function computing the value of a food tile from the size of the snake, see
+    /// [Game::next_food_value].
+    pub fn food_value(mut self, value: fn(usize) -> usize) -> Self {
+        self.game.food_value = value;
+        self
+    }
+
+    /// Sets what the [suggested delay](Game::suggested_delay_ms) between moves is based on.
+    pub fn speed_basis(mut self, basis: SpeedBasis) -> Self {
+        self.game.speed_basis = basis;
+        self
+    }
+
+    /// Sets the number of [moves](Game::move_snake) after every (re)start, during which the snake
+    /// is [invincible](Game::is_invincible).
+    ///
+    /// This gives new players some time to get used to the controls. Since only moves count,
+    /// the grace period doesn't run out while the game is paused, as long as the snake isn't moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction, State};
+    /// let mut game = Game::<10, 10>::builder().start_grace_ticks(7).build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..7 {
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // The snake bumped into the top edge twice, but the grace period is over now.
+    /// assert!(game.state != State::GameOver);
+    /// assert!(!game.is_invincible());
+    /// game.move_snake();
+    /// assert!(game.state == State::GameOver);
+    /// ```
+    pub fn start_grace_ticks(mut self, ticks: u32) -> Self {
+        self.game.start_grace_ticks = ticks;
+        self
+    }
+
+    /// Limits the game to the given number of [ticks](Game::ticks), e.g. for a score attack.
+    ///
+    /// The game is over once the limit is reached, see [Game::ticks_remaining].
+    pub fn tick_limit(mut self, ticks: u64) -> Self {
+        self.game.tick_limit = Some(ticks);
+        self
+    }
+
+    /// Makes the snake shrink by one every given number of [ticks](Game::ticks) it doesn't eat.
+    ///
+    /// Once the snake starves to size zero, the game is over, see [Game::ticks_until_hunger].
+    pub fn hunger_interval(mut self, ticks: u64) -> Self {
+        self.game.hunger_interval = Some(ticks);
+        self
+    }
+
+    /// Shrinks the playable area every given number of [ticks](Game::ticks), by turning the
+    /// outermost ring of tiles into [walls](crate::map::Tile::Wall), until the map is closed.
+    ///
+    /// The game is over if the closing ring catches the snake, see
+    /// [GameOverReason::ArenaClosed](crate::GameOverReason::ArenaClosed). Food caught by the
+    /// ring is placed in the remaining area.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, map::Tile};
+    /// let mut game = Game::<10, 10>::builder().arena_shrink_interval(5).build().unwrap();
+    /// let empty = |game: &Game<10, 10>| game.display(|map| map.count(Tile::Empty));
+    /// let start = empty(&game);
+    ///
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(empty(&game), start);
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.display(|map| map.count(Tile::Wall)), 4 * 9);
+    /// assert_eq!(empty(&game), start - 4 * 9);
+    ///
+    /// for _ in 0..10 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.display(|map| map.count(Tile::Wall)), 100 - 4 * 4);
+    ///
+    /// // The snake in the middle is caught by the innermost ring.
+    /// for _ in 0..10 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::ArenaClosed));
+    ///
+    /// // The ring leaves out AI snakes.
+    /// let mut game = Game::<10, 10>::builder()
+    ///     .arena_shrink_interval(1)
+    ///     .food_count(0)
+    ///     .build()
+    ///     .unwrap();
+    /// game.add_ai_snake(0, 0);
+    /// game.move_snake();
+    /// game.display(|map| {
+    ///     for (x, y) in game.ai_snakes()[0].body() {
+    ///         assert!(map.get(x, y) == Tile::Snake);
+    ///     }
+    /// });
+    /// ```
+    pub fn arena_shrink_interval(mut self, ticks: u64) -> Self {
+        self.game.arena_shrink_interval = Some(ticks);
+        self
+    }
+
+    /// Makes every food tile move to a random adjacent empty tile every given number of
+    /// [ticks](Game::ticks), after the snake moved.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, MoveOutcome, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().mobile_food(1).build().unwrap();
+    /// let food = game.food().to_vec();
+    ///
+    /// // The snake doesn't move without a direction, but the food does.
+    /// game.move_snake();
+    /// assert_ne!(game.food(), food);
+    ///
+    /// // The snake still eats food it catches.
+    /// game.set_tile(5, 4, Tile::Food);
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Ate);
+    /// ```
+    pub fn mobile_food(mut self, interval: u64) -> Self {
+        self.game.mobile_food = Some(interval);
+        self
+    }
+
+    /// Makes food, which wasn't eaten within the given number of [ticks](Game::ticks), move to a
+    /// random location, so it can't be left in a far corner for long.
+    ///
+    /// The moved food counts as new, see [Game::food_age].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::Game;
+    /// let mut game = Game::<10, 10>::builder().food_timeout(5).build().unwrap();
+    /// let food = game.food()[0];
+    ///
+    /// // The snake doesn't move without a direction, but the ticks pass.
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.food(), &[food]);
+    ///
+    /// game.move_snake();
+    /// let moved = game.food()[0];
+    /// assert_ne!(moved, food);
+    /// assert_eq!(game.food_age(moved.0, moved.1), Some(0));
+    ///
+    /// // The timer starts over for the moved food.
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.food(), &[moved]);
+    /// game.move_snake();
+    /// assert_ne!(game.food(), &[moved]);
+    /// ```
+    pub fn food_timeout(mut self, ticks: u64) -> Self {
+        self.game.food_timeout = Some(ticks);
+        self
+    }
+
+    /// Restricts where new food tiles are [created](Game::create_food) to the locations `(x,y)`
+    /// the given function accepts for the current map.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::{Map, Tile}};
+    /// // Keep food away from the snake.
+    /// fn away_from_snake(x: usize, y: usize, map: &Map<5, 5>) -> bool {
+    ///     map.neighbors(x, y).all(|(x, y)| map.get(x, y) != Tile::Snake)
+    /// }
+    ///
+    /// let mut game = Game::<5, 5>::builder()
+    ///     .food_filter(away_from_snake)
+    ///     .food_count(0)
+    ///     .build()
+    ///     .unwrap();
+    /// for _ in 0..20 {
+    ///     assert!(game.create_food());
+    /// }
+    /// for (x, y) in [(1, 2), (3, 2), (2, 1), (2, 3)] {
+    ///     assert!(game.map().get(x, y) == Tile::Empty);
+    /// }
+    ///
+    /// // Every other tile is taken.
+    /// assert!(!game.create_food());
+    /// ```
+    pub fn food_filter(mut self, filter: fn(usize, usize, &Map<W, H>) -> bool) -> Self {
+        self.game.food_filter = Some(filter);
+        self
+    }
+
+    /// Keeps new food tiles at least the given Manhattan distance away from the snake's head, as
+    /// long as there is room for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::Game;
+    /// let mut game = Game::<20, 20>::builder().min_food_distance(5).build().unwrap();
+    /// for _ in 0..50 {
+    ///     game.create_food();
+    /// }
+    /// assert!(game.distance_to_food().unwrap() >= 5);
+    ///
+    /// // On a small map, the food is placed anyway.
+    /// let game = Game::<3, 1>::builder().min_food_distance(5).build().unwrap();
+    /// assert_eq!(game.distance_to_food(), Some(1));
+    /// ```
+    pub fn min_food_distance(mut self, distance: usize) -> Self {
+        self.game.min_food_distance = distance;
+        self
+    }
+
+    /// Hides food further away from the snake's head than the given radius, see
+    /// [Game::food_visible].
+    pub fn fog_radius(mut self, radius: usize) -> Self {
+        self.game.fog_radius = Some(radius);
+        self
+    }
+
+    /// Lets [Game::create_food] place food on the location the snake's tail vacates on the next
+    /// move, which is off by default.
+    ///
+    /// On a crowded board, this leaves room for food, where there wouldn't be any otherwise. The
+    /// food is hidden under the tail until it moved out of the way, so it is off by default to
+    /// only place food where players can see it. The location is skipped while the snake is about
+    /// to grow, since the tail stays in place then.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, MoveOutcome, snake::Direction};
+    /// let crowded = |game: &mut Game<2, 2>| {
+    ///     for dir in [Direction::Up, Direction::Left, Direction::Down] {
+    ///         game.turn_snake(dir);
+    ///         game.move_snake();
+    ///     }
+    /// };
+    ///
+    /// let mut game = Game::<2, 2>::builder().initial_size(4).food_count(0).build().unwrap();
+    /// crowded(&mut game);
+    /// assert!(!game.create_food());
+    ///
+    /// let mut game = Game::<2, 2>::builder()
+    ///     .initial_size(4)
+    ///     .food_count(0)
+    ///     .food_under_tail()
+    ///     .build()
+    ///     .unwrap();
+    /// crowded(&mut game);
+    /// assert!(game.create_food());
+    /// assert_eq!(game.food(), &[(1, 1)]);
+    /// assert_eq!(game.display(|map| map.count(Tile::Food)), 0);
+    ///
+    /// // The head follows the tail onto the food.
+    /// game.turn_snake(Direction::Right);
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Ate);
+    /// ```
+    pub fn food_under_tail(mut self) -> Self {
+        self.game.food_under_tail = true;
+        self
+    }
+
+    /// Turns a random empty tile into a [wall](crate::map::Tile::Wall) whenever the snake eats,
+    /// gradually constricting the board.
+    ///
+    /// The wall is placed after the food is replaced, so it never takes the room needed for the
+    /// new food. Walls are never placed on the snake.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).obstacle_on_eat().build().unwrap();
+    /// for y in 2..5 {
+    ///     game.set_tile(5, y, Tile::Food);
+    /// }
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// for walls in 1..=3 {
+    ///     game.move_snake();
+    ///     assert_eq!(game.display(|map| map.count(Tile::Wall)), walls);
+    /// }
+    /// ```
+    pub fn obstacle_on_eat(mut self) -> Self {
+        self.game.obstacle_on_eat = true;
+        self
+    }
+
+    /// Lets the snake turn around, which [Game::turn_snake] refuses otherwise.
+    ///
+    /// The rule keeping the snake from running into its neck is lifted as well, see
+    /// [Game::move_snake]. So once the snake has a tail, turning around ends the game, unless the
+    /// tail moves out of the way.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).allow_reverse().build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.move_snake();
+    ///
+    /// game.turn_snake(Direction::Down);
+    /// game.move_snake();
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::SelfCollision));
+    /// ```
+    pub fn allow_reverse(mut self) -> Self {
+        self.game.allow_reverse = true;
+        self
+    }
+
+    /// Lets the snake move diagonally, see [Game::turn_snake8].
+    ///
+    /// Diagonal moves change both coordinates at once, so the snake can slip between two of its
+    /// own diagonally adjacent parts.
+    pub fn diagonal(mut self) -> Self {
+        self.game.diagonal = true;
+        self
+    }
+
+    /// Makes the game a sandbox for roaming around freely, e.g. to test movement or rendering.
+    ///
+    /// In a sandbox game:
+    /// - no food is placed, as if the [food count](GameBuilder::food_count) was zero,
+    /// - the snake [wraps](WallBehavior::Wrap) around the map,
+    /// - the snake is always [invincible](Game::is_invincible), so it passes through itself and
+    ///   stays in place instead of running into walls and one-way tiles,
+    /// - the [hunger interval](GameBuilder::hunger_interval) and the
+    ///   [tick limit](GameBuilder::tick_limit) are ignored.
+    ///
+    /// The food count and the wall behavior can still be changed by setting them afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction, State};
+    /// let mut game = Game::<10, 10>::builder().sandbox().tick_limit(5).build().unwrap();
+    /// assert!(game.food().is_empty());
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..100 {
+    ///     game.move_snake();
+    /// }
+    /// assert!(game.state != State::GameOver);
+    /// assert_eq!(game.snake_head(), (5, 5));
+    /// ```
+    pub fn sandbox(mut self) -> Self {
+        self.game.sandbox = true;
+        self.game.border = BorderConfig::uniform(WallBehavior::Wrap);
+        self.food_count = None;
+        self.game.food_count = 0;
+        self
+    }
+
+    /// Seeds the random number generator, so games with the same configuration and the same
+    /// inputs play out identically.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Validates the configuration and creates the game, the same way [Game::new] does.
+    ///
+    /// Returns an [Err] if the [initial size](GameBuilder::initial_size) or the
+    /// [food count](GameBuilder::food_count) was set, but the snake or the food tiles don't fit
+    /// on the map. Like for [Game::new], the defaults are used as far as there is room.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{builder::BuildError, Game};
+    /// let game = Game::<1, 1>::builder().build().unwrap();
+    /// assert_eq!(game.snake_head(), (0, 0));
+    /// assert!(game.food().is_empty());
+    ///
+    /// assert_eq!(
+    ///     Game::<1, 1>::builder().food_count(1).build().err(),
+    ///     Some(BuildError::FoodCount(1))
+    /// );
+    /// ```
+    pub fn build(mut self) -> Result<Game<W, H>, BuildError> {
+        if let Some(size) = self.initial_size {
+            if size == 0 || size > W * H {
+                return Err(BuildError::InitialSize(size));
+            }
+            self.game.initial_size = size;
+        }
+        if let Some(count) = self.food_count {
+            if count >= W * H {
+                return Err(BuildError::FoodCount(count));
+            }
+            self.game.food_count = count;
+        }
+
+        if let Some(seed) = self.seed {
+            self.game.rng = StdRng::seed_from_u64(seed);
+        }
+        self.game.restart();
+
+        Ok(self.game)
+    }
+}
+
+impl<const W: usize, const H: usize> Default for GameBuilder<W, H> {
+    fn default() -> Self {
+        GameBuilder::new()
+    }
+}
+
+/// The reasons a [GameBuilder] can fail to build a [Game].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum BuildError {
+    /// The initial snake size is zero or larger than the map.
+    InitialSize(usize),
+    /// There is no room for the food tiles next to the snake's head.
+    FoodCount(usize),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::InitialSize(size) => {
+                write!(f, "initial snake size {size} doesn't fit on the map")
+            }
+            BuildError::FoodCount(count) => write!(f, "{count} food tiles don't fit on the map"),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
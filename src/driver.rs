@@ -0,0 +1,107 @@
+//! A game loop moving the snake at the [suggested speed](Game::suggested_delay_ms), for frontends
+//! which don't need a loop of their own.
+
+use crate::{snake, Game};
+use std::time::{Duration, Instant};
+
+/// Runs a [Game] until it is over, calling back into the frontend for inputs and rendering.
+///
+/// Every frame, the snake is turned according to the input, moved and the frame is rendered.
+/// Then the driver sleeps for the rest of the [suggested delay](Game::suggested_delay_ms).
+/// Rendering happens on the same thread, so a frame taking longer than the delay slows the game
+/// down. The [slow frame](Driver::on_slow_frame) callback reports these frames, e.g. to render in
+/// less detail.
+///
+/// # Examples
+/// ```
+/// use rust_snake::{driver::Driver, Game, snake::Direction};
+/// use std::time::Duration;
+/// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+/// let mut slow_ticks = Vec::new();
+///
+/// Driver::new(&mut game)
+///     .on_slow_frame(|game, elapsed| {
+///         assert!(elapsed > Duration::from_millis(game.suggested_delay_ms()));
+///         slow_ticks.push(game.ticks());
+///     })
+///     .run(
+///         |_| Some(Direction::Up),
+///         |game| {
+///             // Rendering the second frame takes far too long.
+///             if game.ticks() == 2 {
+///                 let delay = game.suggested_delay_ms();
+///                 std::thread::sleep(Duration::from_millis(3 * delay));
+///             }
+///         },
+///     );
+///
+/// // The snake left the map on the sixth move.
+/// assert_eq!(game.ticks(), 6);
+/// // Other frames may be slow too, on a busy machine.
+/// assert!(slow_ticks.contains(&2));
+/// ```
+pub struct Driver<'a, const W: usize, const H: usize> {
+    game: &'a mut Game<W, H>,
+    on_slow_frame: Option<SlowFrameCallback<'a, W, H>>,
+}
+
+/// The callback reporting slow frames, see [Driver::on_slow_frame].
+type SlowFrameCallback<'a, const W: usize, const H: usize> =
+    Box<dyn FnMut(&Game<W, H>, Duration) + 'a>;
+
+impl<'a, const W: usize, const H: usize> Driver<'a, W, H> {
+    /// Creates a driver for the given game.
+    pub fn new(game: &'a mut Game<W, H>) -> Self {
+        Driver {
+            game,
+            on_slow_frame: None,
+        }
+    }
+
+    /// Sets a callback, which is called with the game and the time the frame took, whenever a
+    /// frame takes longer than the [suggested delay](Game::suggested_delay_ms).
+    pub fn on_slow_frame<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&Game<W, H>, Duration) + 'a,
+    {
+        self.on_slow_frame = Some(Box::new(callback));
+        self
+    }
+
+    /// Runs the game until it is over.
+    ///
+    /// The game is [resumed](Game::set_paused) first. Before every move, the snake is
+    /// [turned](Game::turn_snake) in the direction returned by `input`, unless it returns `None`.
+    /// After every move, `frame` is called to render the game. While the game is
+    /// [paused](Game::is_paused), e.g. by the input, frames are still rendered without moving the
+    /// snake.
+    pub fn run<I, F>(&mut self, mut input: I, mut frame: F)
+    where
+        I: FnMut(&Game<W, H>) -> Option<snake::Direction>,
+        F: FnMut(&Game<W, H>),
+    {
+        self.game.set_paused(false);
+
+        while !self.game.is_over() {
+            let start = Instant::now();
+
+            if let Some(dir) = input(self.game) {
+                self.game.turn_snake(dir);
+            }
+            if self.game.is_running() {
+                self.game.move_snake();
+            }
+            frame(self.game);
+
+            let budget = Duration::from_millis(self.game.suggested_delay_ms());
+            let elapsed = start.elapsed();
+            if elapsed > budget {
+                if let Some(callback) = &mut self.on_slow_frame {
+                    callback(self.game, elapsed);
+                }
+            } else {
+                spin_sleep::sleep(budget - elapsed);
+            }
+        }
+    }
+}
@@ -4,7 +4,8 @@
 //!
 //! A game only handles the logic behind snake. It is up to the programmer, to implement a game
 //! loop, capture user inputs and display the game. However existing functions [Game::move_snake],
-//! [Game::turn_snake] and [Game::display] should make this easy.
+//! [Game::turn_snake] and [Game::display] should make this easy. Alternatively a
+//! [Driver](driver::Driver) runs the loop, calling back for inputs and rendering.
 //!
 //! Note the game has three states, of which only [State::GameOver] is used internally, while
 //! the other two are meant to be interpret and altered by the programmer.
@@ -24,28 +25,483 @@
 //!     });
 //! }
 //! ```
+//!
+//! # Read-only access
+//!
+//! Every method only reading the game takes `&self`, while every method altering it takes
+//! `&mut self`. Renderers, network encoders and other spectators should therefore be handed a
+//! shared reference, which statically prevents them from moving the snake or changing the state.
+//! ```
+//! use rust_snake::{Game, State};
+//!
+//! fn render<const W: usize, const H: usize>(game: &Game<W, H>) {
+//!     let (x, y) = game.snake_head();
+//!     if game.state == State::Paused {
+//!         // Draw a pause screen
+//!     }
+//!     game.display(|map| {
+//!         // Draw the map
+//!     });
+//! }
+//! ```
+//! ```compile_fail
+//! # use rust_snake::Game;
+//! fn render<const W: usize, const H: usize>(game: &Game<W, H>) {
+//!     game.move_snake(); // Doesn't compile
+//! }
+//! ```
+//!
+//! # Logging
+//!
+//! With the `logging` feature, the game emits messages through the [log](https://docs.rs/log)
+//! crate when food is spawned or eaten, the snake turns and the game ends. Without it, the
+//! logging calls are compiled out entirely.
+//!
+//! # Serialization
+//!
+//! With the `serde` feature, the [Snake](snake::Snake) and [Direction](snake::Direction)
+//! implement [serde](https://docs.rs/serde)'s traits. Together with the food locations, a
+//! serialized snake is enough to reconstruct the game's map, see [Game::from_snake].
+//!
+//! The `save` feature adds saving whole games to files and loading them again, see the [save]
+//! module, with a binary format in addition to JSON through the `binary` feature.
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Forwards to `log::debug!` with the `logging` feature and expands to nothing otherwise.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "logging")]
+        log::debug!($($arg)*);
+    };
+}
 
-use rand::Rng;
+/// Forwards to `log::trace!` with the `logging` feature and expands to nothing otherwise.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "logging")]
+        log::trace!($($arg)*);
+    };
+}
 
+pub mod builder;
+pub mod driver;
 pub mod map;
+pub mod replay;
+#[cfg(feature = "save")]
+pub mod save;
+pub mod simulation;
 pub mod snake;
+#[cfg(feature = "terminal")]
+pub mod terminal;
+pub mod walls;
 
 /// The initial size of the snake.
 const INITIAL_SNAKE_SIZE: usize = 3;
+/// The suggested delay between moves of a snake with the initial size, in milliseconds.
+const BASE_DELAY_MS: u64 = 100;
+/// The lowest suggested delay between moves, in milliseconds.
+const MIN_DELAY_MS: u64 = 40;
+/// The number of moves a [boost](Game::request_boost) lasts.
+const BOOST_TICKS: u32 = 10;
+/// The score a [boost](Game::request_boost) costs.
+const BOOST_COST: usize = 1;
+
+/// The default value of a food tile, growing by one for every ten sizes of the snake.
+fn linear_food_value(size: usize) -> usize {
+    1 + size / 10
+}
 
 /// The different states the [Game] can be in.
-#[derive(PartialEq)]
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum State {
     Running,
     Paused,
     GameOver,
 }
 
+impl fmt::Display for State {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            State::Running => "Running",
+            State::Paused => "Paused",
+            State::GameOver => "GameOver",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for State {
+    type Err = ParseStateError;
+
+    /// Parses the name of a `State`, as it is [displayed](fmt::Display).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{ParseStateError, State};
+    /// assert_eq!("Paused".parse(), Ok(State::Paused));
+    /// assert_eq!(State::GameOver.to_string().parse(), Ok(State::GameOver));
+    /// assert_eq!(
+    ///     "Over".parse::<State>(),
+    ///     Err(ParseStateError("Over".to_string()))
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Running" => Ok(State::Running),
+            "Paused" => Ok(State::Paused),
+            "GameOver" => Ok(State::GameOver),
+            _ => Err(ParseStateError(s.to_string())),
+        }
+    }
+}
+
+/// The inconsistencies between the parts passed to [Game::from_parts].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum PartsError {
+    /// The snake's head isn't on the map.
+    HeadOutOfBounds,
+    /// The snake occupies the given location, but there is no snake tile on the map.
+    MissingSnakeTile(usize, usize),
+    /// There is a snake tile at the given location, which the snake doesn't occupy.
+    StraySnakeTile(usize, usize),
+    /// The food at the given location is out of bounds or on the snake.
+    InvalidFood(usize, usize),
+}
+
+impl fmt::Display for PartsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartsError::HeadOutOfBounds => write!(f, "the snake's head is out of bounds"),
+            PartsError::MissingSnakeTile(x, y) => {
+                write!(
+                    f,
+                    "the snake occupies ({x}, {y}), but the map has no snake tile there"
+                )
+            }
+            PartsError::StraySnakeTile(x, y) => {
+                write!(
+                    f,
+                    "the map has a snake tile at ({x}, {y}), which the snake doesn't occupy"
+                )
+            }
+            PartsError::InvalidFood(x, y) => {
+                write!(f, "the food at ({x}, {y}) is out of bounds or on the snake")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PartsError {}
+
+/// The error returned by [Game::recenter] if the center of the map is taken by something other
+/// than the snake or doesn't exist on an empty map, holding the center location.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct RecenterError(pub usize, pub usize);
+
+impl fmt::Display for RecenterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the center ({}, {}) of the map is blocked",
+            self.0, self.1
+        )
+    }
+}
+
+impl std::error::Error for RecenterError {}
+
+/// The error returned by [Game::reverse_snake] if the reversed snake would have no safe move or
+/// its head left the map.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct ReverseError;
+
+impl fmt::Display for ReverseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the reversed snake would have no safe move")
+    }
+}
+
+impl std::error::Error for ReverseError {}
+
+/// The error returned when parsing an unknown [State], holding the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ParseStateError(pub String);
+
+impl fmt::Display for ParseStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown game state \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for ParseStateError {}
+
+/// What happens when the snake tries to leave the map, see [Game::set_wall_behavior].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum WallBehavior {
+    /// The game is over.
+    Die,
+    /// The snake re-enters the map from the opposite side.
+    Wrap,
+    /// The snake [reverses](snake::Snake::reverse) instead of moving.
+    Bounce,
+}
+
+/// What happens at each edge of the map, see [Game::set_border_config].
+///
+/// # Examples
+/// ```
+/// use rust_snake::{BorderConfig, WallBehavior};
+/// let config = BorderConfig::uniform(WallBehavior::Wrap);
+/// assert_eq!(config.left, WallBehavior::Wrap);
+/// assert_eq!(config.bottom, WallBehavior::Wrap);
+/// ```
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct BorderConfig {
+    pub left: WallBehavior,
+    pub right: WallBehavior,
+    pub top: WallBehavior,
+    pub bottom: WallBehavior,
+}
+
+impl BorderConfig {
+    /// Returns a configuration with the same behavior at every edge.
+    pub fn uniform(behavior: WallBehavior) -> Self {
+        BorderConfig {
+            left: behavior,
+            right: behavior,
+            top: behavior,
+            bottom: behavior,
+        }
+    }
+}
+
+/// The reasons for a [GameOver](State).
+///
+/// # Examples
+/// ```
+/// use rust_snake::{Game, GameOverReason, MoveOutcome, State};
+/// use rust_snake::map::{Map, Tile};
+/// use rust_snake::snake::{Direction, Snake};
+/// // Only the food tile is left on the map.
+/// let mut map = Map::<3, 1>::new();
+/// let mut snake = Snake::new(0, 0, 2);
+/// snake.place_head(&mut map);
+/// snake.turn(Direction::Right);
+/// snake.forward();
+/// snake.place_head(&mut map);
+/// map.set(2, 0, Tile::Food);
+///
+/// // Eating it wins the game.
+/// let mut game = Game::from_parts(map, snake, State::Running).unwrap();
+/// game.move_snake();
+/// assert_eq!(game.last_outcome(), MoveOutcome::Ate);
+/// assert_eq!(game.game_over_reason(), Some(GameOverReason::BoardFull));
+/// ```
+#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameOverReason {
+    /// The snake went out of bounds.
+    OutOfBounds,
+    /// The snake ran into itself.
+    SelfCollision,
+    /// The snake ran into an [AI snake](Game::add_ai_snake).
+    HitAiSnake,
+    /// The snake entered a [one-way](map::Tile::OneWay) tile from the wrong side.
+    Blocked,
+    /// The snake ran into a [wall](map::Tile::Wall).
+    HitWall,
+    /// The snake [shrunk](Game::shrink_snake) to size zero.
+    Shrunk,
+    /// The configured [tick limit](builder::GameBuilder::tick_limit) was reached.
+    TimeUp,
+    /// The snake ate the last food tile and there is no room for another one, so the game is won.
+    ///
+    /// This includes eating a food tile on the last free location of the map.
+    BoardFull,
+    /// The [shrinking arena](builder::GameBuilder::arena_shrink_interval) closed in on the snake.
+    ArenaClosed,
+    /// The map has no locations, since its width or height is zero, so the game is over right
+    /// after (re)starting.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, State, WallBehavior, snake::Direction};
+    /// let mut game = Game::<0, 10>::new();
+    /// assert!(game.state == State::GameOver);
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::EmptyMap));
+    /// assert!(game.food().is_empty());
+    ///
+    /// game.move_snake();
+    /// game.restart();
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::EmptyMap));
+    /// assert!(Game::<0, 0>::new().state == State::GameOver);
+    ///
+    /// // Queries about the next move don't panic.
+    /// game.set_wall_behavior(WallBehavior::Wrap);
+    /// assert!(!game.is_safe(Direction::Up));
+    /// assert!(game.safe_directions().is_empty());
+    /// assert_eq!(game.danger_level(), 3);
+    /// assert_eq!(game.reachable_space(Direction::Up), 0);
+    /// assert!(game.lethal_cells().is_empty());
+    /// assert_eq!(game.neighbors(0, 0).count(), 0);
+    /// ```
+    EmptyMap,
+    /// The game was ended through [Game::game_over].
+    Quit,
+}
+
+/// What the [suggested delay](Game::suggested_delay_ms) between moves is based on.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum SpeedBasis {
+    /// The snake gets faster the more it grew.
+    Size,
+    /// The snake gets faster the higher the [score](Game::score) is.
+    Score,
+    /// The growth and the score both count.
+    Both,
+}
+
+/// A remapping of the directions passed to [Game::turn_snake], e.g. for an inverted controls mode.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum ControlTransform {
+    /// Directions are used as they are.
+    Identity,
+    /// Directions are rotated by 90 degrees clockwise.
+    RotateCw,
+    /// Directions are rotated by 90 degrees counterclockwise.
+    RotateCcw,
+    /// Directions are [inverted](snake::Direction::inverse).
+    Invert,
+    /// Left and right are swapped.
+    MirrorHorizontal,
+    /// Up and down are swapped.
+    MirrorVertical,
+}
+
+impl ControlTransform {
+    /// Returns the given direction remapped by this transform.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{ControlTransform, snake::Direction};
+    /// assert_eq!(ControlTransform::RotateCw.apply(Direction::Left), Direction::Up);
+    /// assert_eq!(ControlTransform::MirrorVertical.apply(Direction::Left), Direction::Left);
+    /// assert_eq!(ControlTransform::Invert.apply(Direction::None), Direction::None);
+    /// ```
+    pub fn apply(&self, dir: snake::Direction) -> snake::Direction {
+        match self {
+            ControlTransform::Identity => dir,
+            ControlTransform::RotateCw => dir.rotate_cw(),
+            ControlTransform::RotateCcw => dir.rotate_ccw(),
+            ControlTransform::Invert => dir.inverse(),
+            ControlTransform::MirrorHorizontal => match dir {
+                snake::Direction::Left | snake::Direction::Right => dir.inverse(),
+                _ => dir,
+            },
+            ControlTransform::MirrorVertical => match dir {
+                snake::Direction::Up | snake::Direction::Down => dir.inverse(),
+                _ => dir,
+            },
+        }
+    }
+}
+
+/// The outcome of the [last move](Game::last_outcome).
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum MoveOutcome {
+    /// The snake moved onto an empty tile.
+    Moved,
+    /// The snake ate a food tile.
+    Ate,
+    /// The snake died during the move.
+    Died,
+    /// The snake didn't move, e.g. because it has no direction yet or the game is over.
+    Idle,
+}
+
+/// The events recorded in the [event log](Game::event_log).
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum GameEvent {
+    /// The snake changed its direction.
+    Turned(snake::Direction),
+    /// The snake ate the food at the given location.
+    Ate(usize, usize),
+    /// The snake died for the given reason and respawned, using up one of its
+    /// [lives](Game::lives).
+    LifeLost(GameOverReason),
+    /// The game ended.
+    GameOver(GameOverReason),
+}
+
+/// A part of the snake and how it connects to its neighbors, see [Game::body_segments].
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Segment {
+    /// The location of the part.
+    pub location: (usize, usize),
+    /// The direction the snake moved in to enter the location, or
+    /// [None](snake::Direction::None) for the oldest tail piece.
+    pub incoming: snake::Direction,
+    /// The direction the snake moved in to leave the location, or
+    /// [None](snake::Direction::None) for the head.
+    pub outgoing: snake::Direction,
+}
+
 /// Struct representing the state of the game and offering methods to alter it.
 pub struct Game<const W: usize, const H: usize> {
     map: map::Map<W, H>,
     snake: snake::Snake,
     pub state: State,
+    invincible_ticks: u32,
+    start_grace_ticks: u32,
+    border: BorderConfig,
+    events: Vec<GameEvent>,
+    rng: StdRng,
+    initial_size: usize,
+    growth: usize,
+    food_count: usize,
+    game_over_reason: Option<GameOverReason>,
+    ticks: u64,
+    tick_limit: Option<u64>,
+    score: usize,
+    hunger_interval: Option<u64>,
+    hunger: u64,
+    last_outcome: MoveOutcome,
+    boost_ticks: u32,
+    food: Vec<(usize, usize)>,
+    mobile_food: Option<u64>,
+    food_timeout: Option<u64>,
+    fog_radius: Option<usize>,
+    food_value: fn(usize) -> usize,
+    start_rng: StdRng,
+    food_filter: Option<fn(usize, usize, &map::Map<W, H>) -> bool>,
+    sandbox: bool,
+    speed_basis: SpeedBasis,
+    pending_growth: usize,
+    min_food_distance: usize,
+    ai_snakes: Vec<snake::Snake>,
+    control_transform: ControlTransform,
+    diagonal: bool,
+    heading: Option<snake::Direction8>,
+    foods_eaten: usize,
+    boosts: usize,
+    allow_reverse: bool,
+    obstacle_on_eat: bool,
+    last_food_tick: u64,
+    arena_shrink_interval: Option<u64>,
+    arena_inset: usize,
+    food_enabled: bool,
+    food_under_tail: bool,
+    death_cell: Option<(isize, isize)>,
+    food_spawn_ticks: HashMap<(usize, usize), u64>,
+    max_size: Option<usize>,
+    max_lives: u32,
+    lives: u32,
 }
 
 impl<const W: usize, const H: usize> Game<W, H> {
@@ -53,129 +509,3158 @@ impl<const W: usize, const H: usize> Game<W, H> {
     /// [paused](State).
     ///
     /// The head of the snake will be placed on the map and a food tile will be
-    /// [generated](Game::create_food).
+    /// [generated](Game::create_food), if there is room for it. Maps without any locations are
+    /// over right away, see [GameOverReason::EmptyMap].
+    ///
+    /// Use a [builder](Game::builder) to configure the game instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, snake::Direction, State};
+    /// let mut game = Game::<1, 1>::new();
+    /// assert_eq!(game.snake_head(), (0, 0));
+    /// assert!(game.food().is_empty());
+    ///
+    /// game.set_paused(false);
+    /// game.turn_snake(Direction::Right);
+    /// game.move_snake();
+    /// assert!(game.state == State::GameOver);
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::OutOfBounds));
+    /// ```
     pub fn new() -> Self {
-        let mut game = Game {
-            map: map::Map::<W, H>::new(),
-            snake: snake::Snake::new(W / 2, H / 2, INITIAL_SNAKE_SIZE),
-            state: State::Paused,
-        };
+        let mut game = Game::with_rng(StdRng::from_entropy());
+        game.restart();
+
+        game
+    }
 
-        game.snake.place_head(&mut game.map);
-        game.create_food();
+    /// Like [Game::new], but seeds the random number generator before the first food tile is
+    /// placed, so the game is reproducible from the start.
+    ///
+    /// This is a shorthand for a [builder](Game::builder) with only a
+    /// [seed](builder::GameBuilder::seed).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::Game;
+    /// let game = Game::<10, 10>::new_seeded(42);
+    /// assert_eq!(game.food(), Game::<10, 10>::new_seeded(42).food());
+    /// ```
+    pub fn new_seeded(seed: u64) -> Self {
+        let mut game = Game::with_rng(StdRng::seed_from_u64(seed));
+        game.restart();
 
         game
     }
 
-    /// Tries to turn the snake in the given direction, see [snake::Snake::turn].
-    pub fn turn_snake(&mut self, dir: snake::Direction) {
-        self.snake.turn(dir);
+    /// Creates a game from a prepared map and snake in the given state, e.g. to load a level.
+    ///
+    /// The snake isn't moved to the middle and no food is generated, instead the food tiles on the
+    /// map are used. Every other setting has its default value.
+    ///
+    /// Returns an [Err] if the snake's head is out of bounds, unless the game is over, or the
+    /// [snake tiles](map::Tile::Snake) on the map don't match the snake's locations.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, PartsError, State};
+    /// use rust_snake::map::{Map, Tile};
+    /// use rust_snake::snake::{Direction, Snake};
+    /// let mut map = Map::<10, 10>::new();
+    /// let mut snake = Snake::new(1, 1, 3);
+    /// snake.place_head(&mut map);
+    /// snake.turn(Direction::Right);
+    /// snake.forward();
+    /// snake.place_head(&mut map);
+    /// map.set(5, 1, Tile::Food);
+    ///
+    /// let game = Game::from_parts(map, snake, State::Running).unwrap();
+    /// assert_eq!(game.snake_head(), (2, 1));
+    /// assert_eq!(game.food(), [(5, 1)]);
+    ///
+    /// // The map is missing the snake's head.
+    /// let snake = Snake::new(2, 1, 3);
+    /// assert_eq!(
+    ///     Game::from_parts(Map::<10, 10>::new(), snake, State::Running).err(),
+    ///     Some(PartsError::MissingSnakeTile(2, 1))
+    /// );
+    /// ```
+    pub fn from_parts(
+        map: map::Map<W, H>,
+        mut snake: snake::Snake,
+        state: State,
+    ) -> Result<Self, PartsError> {
+        // A snake which left the map ended the game with its head out of bounds.
+        let head_out = !snake.in_bounds(&map);
+        if head_out && state != State::GameOver {
+            return Err(PartsError::HeadOutOfBounds);
+        }
+        if head_out {
+            snake.leave_map();
+        }
+        if let Some((x, y)) = snake
+            .body()
+            .find(|&(x, y)| !map.in_bounds(x, y) || map.get(x, y) != map::Tile::Snake)
+        {
+            return Err(PartsError::MissingSnakeTile(x, y));
+        }
+        if let Some((x, y)) = map
+            .locations(map::Tile::Snake)
+            .find(|&(x, y)| !snake.occupies(x, y))
+        {
+            return Err(PartsError::StraySnakeTile(x, y));
+        }
+
+        let mut game = Game::with_rng(StdRng::from_entropy());
+        game.food = map.locations(map::Tile::Food).collect();
+        game.map = map;
+        game.snake = snake;
+        game.state = state;
+        game.start_rng = game.rng.clone();
+
+        Ok(game)
     }
 
-    /// Moves the snake forward.
+    /// Creates a game from a snake and the locations of the food tiles in the given state, like
+    /// [Game::from_parts] with a map containing only these.
     ///
-    /// If the snake touches a food tile, the size of the snake will increase by one.
-    /// The game state will be set to [GameOver](State) if the snake goes out of bounds or touches
-    /// itself.
+    /// Since the snake's tiles follow from the snake itself, it is enough to send the snake and the
+    /// food to reconstruct the map, e.g. to synchronize games over the network.
     ///
-    /// Additionally the map will be updated accordingly.
-    pub fn move_snake(&mut self) {
-        // Move the snake.
-        self.snake.forward();
-        self.snake.cut_tail(&mut self.map);
+    /// Returns an [Err] if the snake's head is out of bounds or a food tile is out of bounds or
+    /// on the snake.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "serde")]
+    /// # {
+    /// use rust_snake::{Game, snake::{Direction, Snake}, State};
+    /// let mut game = Game::<10, 10>::new_seeded(5);
+    /// for dir in [Direction::Up, Direction::Left, Direction::Left, Direction::Down] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // Only the snake and the food locations are sent.
+    /// let json = serde_json::to_string(game.snake()).unwrap();
+    /// let snake: Snake = serde_json::from_str(&json).unwrap();
+    ///
+    /// let rebuilt = Game::<10, 10>::from_snake(snake, game.food(), game.state).unwrap();
+    /// assert!(rebuilt.map() == game.map());
+    /// assert_eq!(rebuilt.snake_direction(), game.snake_direction());
+    /// # }
+    /// ```
+    /// ```
+    /// use rust_snake::{Game, PartsError, snake::Snake, State};
+    /// let snake = Snake::new(2, 2, 3);
+    /// assert_eq!(
+    ///     Game::<5, 5>::from_snake(snake, &[(2, 2)], State::Paused).err(),
+    ///     Some(PartsError::InvalidFood(2, 2))
+    /// );
+    /// ```
+    pub fn from_snake(
+        snake: snake::Snake,
+        food: &[(usize, usize)],
+        state: State,
+    ) -> Result<Self, PartsError> {
+        let mut map = map::Map::new();
+        if !snake.in_bounds(&map) {
+            return Err(PartsError::HeadOutOfBounds);
+        }
 
-        // Check if its in bounds and colliding with something.
-        if self.snake.in_bounds(&self.map) {
-            match self.snake.touching_tile(&self.map) {
-                map::Tile::Snake => {
-                    // The snake ran into itself, game over.
-                    self.game_over();
-                }
-                map::Tile::Food => {
-                    // Increase the snake size and create a new food tile.
-                    self.snake.size += 1;
-                    self.create_food();
-                }
-                map::Tile::Empty => (),
+        for (x, y) in snake.body() {
+            if map.try_set(x, y, map::Tile::Snake).is_err() {
+                return Err(PartsError::MissingSnakeTile(x, y));
             }
-
-            // Update the snake head on the map
-            self.snake.place_head(&mut self.map);
-        } else {
-            // The snake went out of bounds, game over.
-            self.game_over();
         }
+        for &(x, y) in food {
+            if map.try_get(x, y) != Ok(map::Tile::Empty) {
+                return Err(PartsError::InvalidFood(x, y));
+            }
+            map.set(x, y, map::Tile::Food);
+        }
+
+        Game::from_parts(map, snake, state)
     }
 
-    /// Create a food tile on a random, previously unoccupied space.
-    pub fn create_food(&mut self) {
-        let mut rng = rand::thread_rng();
+    /// The snake, e.g. to [serialize](snake::Snake) it.
+    pub fn snake(&self) -> &snake::Snake {
+        &self.snake
+    }
+
+    /// Creates a [GameBuilder](builder::GameBuilder) for a configured game.
+    pub fn builder() -> builder::GameBuilder<W, H> {
+        builder::GameBuilder::new()
+    }
 
-        // Loop through random locations until an applicable one is found.
-        loop {
-            let fx = rng.gen_range(0..W);
-            let fy = rng.gen_range(0..H);
+    /// Creates a game with the default configuration, using the given random number generator.
+    ///
+    /// Note the snake and food aren't placed on the map, until the game is
+    /// [restarted](Game::restart).
+    fn with_rng(rng: StdRng) -> Self {
+        Game {
+            map: map::Map::<W, H>::new(),
+            snake: snake::Snake::new(W / 2, H / 2, INITIAL_SNAKE_SIZE),
+            state: State::Paused,
+            invincible_ticks: 0,
+            start_grace_ticks: 0,
+            border: BorderConfig::uniform(WallBehavior::Die),
+            events: Vec::new(),
+            start_rng: rng.clone(),
+            rng,
+            initial_size: INITIAL_SNAKE_SIZE,
+            growth: 1,
+            food_count: 1,
+            game_over_reason: None,
+            ticks: 0,
+            tick_limit: None,
+            score: 0,
+            hunger_interval: None,
+            hunger: 0,
+            last_outcome: MoveOutcome::Idle,
+            boost_ticks: 0,
+            food: Vec::new(),
+            mobile_food: None,
+            food_timeout: None,
+            fog_radius: None,
+            food_value: linear_food_value,
+            food_filter: None,
+            sandbox: false,
+            speed_basis: SpeedBasis::Size,
+            pending_growth: 0,
+            min_food_distance: 0,
+            ai_snakes: Vec::new(),
+            control_transform: ControlTransform::Identity,
+            diagonal: false,
+            heading: None,
+            foods_eaten: 0,
+            boosts: 0,
+            allow_reverse: false,
+            obstacle_on_eat: false,
+            last_food_tick: 0,
+            arena_shrink_interval: None,
+            arena_inset: 0,
+            food_enabled: true,
+            food_under_tail: false,
+            death_cell: None,
+            food_spawn_ticks: HashMap::new(),
+            max_size: None,
+            max_lives: 1,
+            lives: 1,
+        }
+    }
 
-            // End the loop and place the food, if the tile is unoccupied.
-            if self.map.get(fx, fy) == map::Tile::Empty {
-                self.map.set(fx, fy, map::Tile::Food);
+    /// Tries to turn the snake in the given direction, see [snake::Snake::turn].
+    ///
+    /// The direction is remapped by the [ControlTransform] first. Turning around is only possible
+    /// if [reversing](builder::GameBuilder::allow_reverse) is allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.turn_snake(Direction::Up);
+    /// game.turn_snake(Direction::Down);
+    /// assert_eq!(game.snake_direction(), Direction::Up);
+    ///
+    /// let mut game = Game::<10, 10>::builder().allow_reverse().build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// game.turn_snake(Direction::Down);
+    /// assert_eq!(game.snake_direction(), Direction::Down);
+    /// ```
+    pub fn turn_snake(&mut self, dir: snake::Direction) {
+        self.turn_snake_untransformed(self.control_transform.apply(dir));
+    }
 
-                break;
+    /// Like [Game::turn_snake], but without the [ControlTransform], e.g. to play back directions
+    /// which were already remapped.
+    pub(crate) fn turn_snake_untransformed(&mut self, dir: snake::Direction) {
+        // Leave a diagonal heading, unless turning around.
+        if let Some(heading) = self.heading {
+            if heading.opposite(dir.into()) && !self.allow_reverse {
+                return;
             }
+            self.heading = None;
+        }
+
+        let previous = self.snake.direction();
+        if self.allow_reverse {
+            self.snake.set_direction(dir);
+        } else {
+            self.snake.turn(dir);
+        }
+
+        if self.snake.direction() != previous {
+            trace!("snake turned {}", dir);
+            self.events.push(GameEvent::Turned(dir));
         }
     }
 
-    /// Calls the given function with the map of this game, containing empty, snake and food
-    /// [tiles](map::Tile).
-    ///
-    /// This function is intended to only display the state of the map, therefor it receives an
-    /// immutable reference.
+    /// Tries to turn the snake in any of the eight directions, in a game with
+    /// [diagonal movement](builder::GameBuilder::diagonal).
     ///
-    /// Note this method is not innately concurrent, so expensive rendering should be handled
-    /// with care.
+    /// Orthogonal directions are handled by [Game::turn_snake]. Diagonal ones are ignored if
+    /// diagonal movement is disabled or they [oppose](snake::Direction8::opposite) the
+    /// [heading](Game::snake_heading). They aren't remapped by the [ControlTransform] and
+    /// don't record a [turn](GameEvent::Turned).
     ///
     /// # Examples
     /// ```
-    /// use rust_snake::{Game, map};
-    /// let game = Game::<10, 10>::new();
-    /// game.display(|map| {
-    ///     for x in 0..10 {
-    ///         for y in 0..10 {
-    ///             match map.get(x, y) {
-    ///                 // display the tile accordingly
-    ///                 map::Tile::Empty => (),
-    ///                 // ...
-    ///                 # _ => ()
-    ///             }
-    ///         }
-    ///     }
-    /// })
-    pub fn display<F, R>(&self, func: F) -> R
-    where
-        F: FnOnce(&map::Map<W, H>) -> R,
-    {
-        func(&self.map)
+    /// use rust_snake::{Game, snake::Direction8};
+    /// let mut game = Game::<10, 10>::builder().diagonal().food_count(0).build().unwrap();
+    /// game.turn_snake8(Direction8::UpRight);
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert_eq!(game.snake_head(), (7, 3));
+    ///
+    /// // Turning around is refused.
+    /// game.turn_snake8(Direction8::DownLeft);
+    /// assert_eq!(game.snake_heading(), Direction8::UpRight);
+    ///
+    /// game.turn_snake8(Direction8::Left);
+    /// game.move_snake();
+    /// assert_eq!(game.snake_head(), (6, 3));
+    /// ```
+    pub fn turn_snake8(&mut self, dir: snake::Direction8) {
+        match dir.orthogonal() {
+            Some(dir) => self.turn_snake(dir),
+            None if self.diagonal
+                && (self.allow_reverse || !self.snake_heading().opposite(dir)) =>
+            {
+                self.heading = Some(dir);
+                self.snake.set_direction(snake::Direction::None);
+            }
+            None => (),
+        }
     }
 
-    /// Gets called when the snake moves out of bounds or into itself.
-    ///
-    /// Currently this method only sets the game state to [GameOver](State).
-    pub fn game_over(&mut self) {
-        self.state = State::GameOver;
+    /// The [Direction8](snake::Direction8) the snake is heading, which is only diagonal in a
+    /// game with [diagonal movement](builder::GameBuilder::diagonal).
+    pub fn snake_heading(&self) -> snake::Direction8 {
+        self.heading
+            .unwrap_or_else(|| self.snake.direction().into())
     }
 
-    /// Clears the map, initializes a new snake and sets the state to [Paused](State).
-    pub fn restart(&mut self) {
-        for x in 0..W {
-            for y in 0..H {
-                self.map.set(x, y, map::Tile::Empty);
-            }
+    /// Returns if the snake can turn around, see
+    /// [GameBuilder::allow_reverse](builder::GameBuilder::allow_reverse).
+    pub fn allows_reverse(&self) -> bool {
+        self.allow_reverse
+    }
+
+    /// Returns if the snake can move diagonally, see
+    /// [GameBuilder::diagonal](builder::GameBuilder::diagonal).
+    pub fn is_diagonal(&self) -> bool {
+        self.diagonal
+    }
+
+    /// Moves the snake forward.
+    ///
+    /// If the snake touches a food tile, the size of the snake will increase by the configured
+    /// [growth](builder::GameBuilder::growth), which is one by default.
+    /// The game state will be set to [GameOver](State) if the snake goes out of bounds, touches
+    /// itself or runs into a [wall](map::Tile::Wall).
+    ///
+    /// [One-way](map::Tile::OneWay) tiles facing the snake's direction are passed through, while
+    /// entering them from any other direction is fatal.
+    ///
+    /// What happens at the map boundaries depends on the [WallBehavior]. While the snake is
+    /// [invincible](Game::is_invincible), it passes through itself and stays in place instead of
+    /// dying at the boundaries or in a one-way tile.
+    ///
+    /// The snake never runs into its neck, the part directly behind the head. If it faces its neck,
+    /// e.g. after stopping and turning around, it keeps going the way it came from instead. This
+    /// doesn't apply if [reversing](builder::GameBuilder::allow_reverse) is allowed, so turning
+    /// around runs into the neck, unless the tail vacates it during the move.
+    ///
+    /// While the snake faces [None](snake::Direction), it stays in place, see
+    /// [Game::last_outcome]. Once the game is over, calls are ignored. Every other call counts as
+    /// one [tick](Game::ticks), so the game ends after the configured
+    /// [tick limit](builder::GameBuilder::tick_limit) and the snake
+    /// [starves](builder::GameBuilder::hunger_interval) if it doesn't eat regularly.
+    ///
+    /// Additionally the map will be updated accordingly.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction, State};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.set_tile(5, 4, Tile::OneWay(Direction::Up));
+    /// game.set_tile(5, 2, Tile::OneWay(Direction::Down));
+    ///
+    /// // Pass through the first one-way tile.
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert_eq!(game.snake_head(), (5, 3));
+    ///
+    /// // Get blocked by the second one.
+    /// game.move_snake();
+    /// assert!(game.state == State::GameOver);
+    /// ```
+    ///
+    /// Turning around after stopping doesn't count as running into the neck:
+    /// ```
+    /// use rust_snake::{Game, MoveOutcome, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.turn_snake(Direction::None);
+    /// game.turn_snake(Direction::Down);
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Moved);
+    /// assert_eq!(game.snake_head(), (5, 3));
+    /// assert_eq!(game.snake_direction(), Direction::Up);
+    /// ```
+    ///
+    /// The tail moves out of the way during the move, so the snake can follow its own tail closely:
+    /// ```
+    /// use rust_snake::{Game, MoveOutcome, snake::Direction, State};
+    /// let mut game = Game::<10, 10>::builder().initial_size(4).food_count(0).build().unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // Turning into the tile the tail is just leaving is safe.
+    /// assert!(game.is_safe(Direction::Right));
+    /// game.turn_snake(Direction::Right);
+    /// game.move_snake();
+    /// assert_eq!(game.snake_head(), (5, 5));
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Moved);
+    /// assert!(game.state != State::GameOver);
+    /// ```
+    ///
+    /// If there is [food under the tail](builder::GameBuilder::food_under_tail), the food takes
+    /// precedence: the snake eats it and continues, rather than running into its tail.
+    /// ```
+    /// use rust_snake::{Game, MoveOutcome, snake::Direction};
+    /// let mut game = Game::<3, 2>::builder()
+    ///     .initial_size(4)
+    ///     .food_count(0)
+    ///     .food_under_tail()
+    ///     .food_filter(|x, _, _| x < 2)
+    ///     .build()
+    ///     .unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // No food is placed under the tail, while the head is about to move there.
+    /// game.turn_snake(Direction::Right);
+    /// assert!(!game.create_food());
+    ///
+    /// game.turn_snake(Direction::Down);
+    /// assert!(game.create_food());
+    /// assert_eq!(game.food(), &[(1, 1)]);
+    ///
+    /// game.turn_snake(Direction::Right);
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Ate);
+    /// assert_eq!(game.snake_head(), (1, 1));
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Moved);
+    /// assert_eq!(game.snake_head(), (2, 1));
+    /// ```
+    pub fn move_snake(&mut self) {
+        if self.state == State::GameOver {
+            self.last_outcome = MoveOutcome::Idle;
+            return;
         }
 
-        self.snake = snake::Snake::new(W / 2, H / 2, INITIAL_SNAKE_SIZE);
-        self.snake.place_head(&mut self.map);
-        self.create_food();
-        self.state = State::Paused;
+        self.ticks += 1;
+        self.boost_ticks = self.boost_ticks.saturating_sub(1);
+        self.last_outcome = self.move_forward();
+
+        if self.state != State::GameOver {
+            self.digest(self.last_outcome == MoveOutcome::Ate);
+
+            // The snake starved.
+            if self.state == State::GameOver {
+                self.last_outcome = MoveOutcome::Died;
+            }
+        }
+        if self.state != State::GameOver {
+            self.move_ai_snakes();
+        }
+        if self.state != State::GameOver
+            && self
+                .mobile_food
+                .is_some_and(|interval| self.ticks.is_multiple_of(interval))
+        {
+            self.move_food();
+        }
+        if self.state != State::GameOver {
+            self.replace_stale_food();
+        }
+        if self.state != State::GameOver
+            && self
+                .arena_shrink_interval
+                .is_some_and(|interval| self.ticks.is_multiple_of(interval))
+        {
+            self.close_arena();
+        }
+        if self.state != State::GameOver && !self.sandbox && self.ticks_remaining() == Some(0) {
+            self.end(GameOverReason::TimeUp);
+        }
+    }
+
+    /// Moves every food tile to a random adjacent empty tile, if there is one.
+    fn move_food(&mut self) {
+        for i in 0..self.food.len() {
+            let (x, y) = self.food[i];
+            // Food under the tail stays there, until the tail moved out of the way.
+            if self.map.get(x, y) != map::Tile::Food {
+                continue;
+            }
+
+            let free: Vec<_> = self
+                .neighbors(x, y)
+                .filter(|&(x, y)| self.map.get(x, y) == map::Tile::Empty)
+                .collect();
+
+            if free.is_empty() {
+                continue;
+            }
+            let (nx, ny) = free[self.rng.gen_range(0..free.len())];
+
+            self.map.set(x, y, map::Tile::Empty);
+            self.map.set(nx, ny, map::Tile::Food);
+            self.food[i] = (nx, ny);
+
+            // Moving food keeps its age.
+            let spawned = self.food_spawn_ticks.remove(&(x, y)).unwrap_or(0);
+            self.food_spawn_ticks.insert((nx, ny), spawned);
+        }
+    }
+
+    /// Replaces every food tile, which is older than the
+    /// [food timeout](builder::GameBuilder::food_timeout), with a newly
+    /// [created](Game::create_food) one.
+    ///
+    /// Food is only removed once its replacement was placed, so it stays if there is no room.
+    fn replace_stale_food(&mut self) {
+        let Some(timeout) = self.food_timeout else {
+            return;
+        };
+
+        let stale: Vec<_> = self
+            .food
+            .iter()
+            .copied()
+            .filter(|&(x, y)| self.food_age(x, y).is_some_and(|age| age >= timeout))
+            .collect();
+        for (x, y) in stale {
+            // Food under the tail stays there, until the tail moved out of the way.
+            if self.map.get(x, y) != map::Tile::Food || !self.create_food() {
+                continue;
+            }
+
+            trace!("food at ({}, {}) timed out", x, y);
+            self.map.set(x, y, map::Tile::Empty);
+            self.food.retain(|&food| food != (x, y));
+            self.food_spawn_ticks.remove(&(x, y));
+        }
+    }
+
+    /// Advances the hunger timer, shrinking the snake by one once it runs out.
+    ///
+    /// Eating resets the timer.
+    fn digest(&mut self, ate: bool) {
+        if self.hunger_interval.is_none() || self.sandbox {
+            return;
+        }
+
+        self.hunger = if ate { 0 } else { self.hunger + 1 };
+        if self.ticks_until_hunger() == Some(0) {
+            self.hunger = 0;
+            self.shrink_snake(1);
+        }
+    }
+
+    /// Moves the snake forward and handles collisions, see [Game::move_snake].
+    fn move_forward(&mut self) -> MoveOutcome {
+        // A snake without any size can't move.
+        if self.snake.size == 0 {
+            self.end(GameOverReason::Shrunk);
+            return MoveOutcome::Died;
+        }
+
+        // A snake without a direction stays in place.
+        if self.snake_heading() == snake::Direction8::None {
+            return MoveOutcome::Idle;
+        }
+
+        // The snake can't move back onto its neck, e.g. after stopping and turning around, so it
+        // keeps going the way it came from instead.
+        if let (Some(neck), None, false) = (self.snake.neck(), self.heading, self.allow_reverse) {
+            let (nx, ny) = (neck.0 as isize, neck.1 as isize);
+            if self.destination(self.snake.direction()) == (nx, ny) {
+                let head = (self.snake.x() as usize, self.snake.y() as usize);
+                let away = self.direction_between(neck, head);
+                self.snake.set_direction(away);
+            }
+        }
+
+        let invincible = self.is_invincible();
+        self.invincible_ticks = self.invincible_ticks.saturating_sub(1);
+
+        let (x, y) = match self.heading {
+            Some(heading) => self.step_by(self.snake.x(), self.snake.y(), heading.x(), heading.y()),
+            None => self.destination(self.snake.direction()),
+        };
+
+        // Turn around at the boundaries, taking up this move.
+        if self.edge_behavior(x, y) == Some(WallBehavior::Bounce) {
+            self.snake.reverse();
+            self.heading = self.heading.map(|heading| heading.inverse());
+            self.events.push(GameEvent::Turned(self.snake.direction()));
+            return MoveOutcome::Idle;
+        }
+
+        // Bump into the boundary, a wall, a one-way tile or an AI snake without moving, if the
+        // snake is invincible.
+        let obstacle = match self.tile_at(x, y) {
+            None | Some(map::Tile::OneWay(_) | map::Tile::Wall) => true,
+            Some(map::Tile::Snake) => !self.snake.occupies(x as usize, y as usize),
+            _ => false,
+        };
+        if invincible && obstacle {
+            return MoveOutcome::Idle;
+        }
+
+        // Grow by one queued segment, so the tail stays in place this move.
+        if self.pending_growth > 0 {
+            self.pending_growth -= 1;
+            self.grow(1);
+        }
+
+        // The tail moves out of the way, so the tile it vacates during this move can be entered.
+        let follows_tail = self
+            .snake
+            .vacated_next()
+            .is_some_and(|(tx, ty)| (tx as isize, ty as isize) == (x, y));
+
+        // Eating the food on the last free location fills the board, even though the tail vacates
+        // a location during the move. Only count the empty tiles when eating the last food.
+        let eats_last_food = self.tile_at(x, y) == Some(map::Tile::Food) && self.food.len() == 1;
+        let fills_board = eats_last_food && self.map.count(map::Tile::Empty) == 0;
+
+        // Move the snake.
+        self.snake.forward_to(x, y);
+        self.snake.cut_tail(&mut self.map);
+        self.reveal_food();
+
+        // Check if its in bounds and colliding with something.
+        if !self.snake.in_bounds(&self.map) {
+            // The snake went out of bounds, game over.
+            self.snake.leave_map();
+            self.end(GameOverReason::OutOfBounds);
+            return MoveOutcome::Died;
+        }
+
+        let tile = match self.snake.touching_tile(&self.map) {
+            // Food may have been placed under the tail.
+            map::Tile::Snake if follows_tail && self.food.contains(&(x as usize, y as usize)) => {
+                map::Tile::Food
+            }
+            map::Tile::Snake if follows_tail => map::Tile::Empty,
+            tile => tile,
+        };
+        match tile {
+            map::Tile::OneWay(_) => {
+                // The snake entered a one-way tile from the wrong side, game over.
+                self.end(GameOverReason::Blocked);
+                return MoveOutcome::Died;
+            }
+            map::Tile::Wall => {
+                // The snake ran into a wall, game over.
+                self.end(GameOverReason::HitWall);
+                return MoveOutcome::Died;
+            }
+            _ => (),
+        }
+
+        // Update the snake head on the map
+        self.snake.place_head(&mut self.map);
+
+        match tile {
+            map::Tile::Snake if !invincible => {
+                // The snake ran into itself or an AI snake, game over.
+                let on_ai_snake = self
+                    .ai_snakes
+                    .iter()
+                    .any(|ai| ai.occupies(x as usize, y as usize));
+                self.end(if on_ai_snake {
+                    GameOverReason::HitAiSnake
+                } else {
+                    GameOverReason::SelfCollision
+                });
+                MoveOutcome::Died
+            }
+            map::Tile::Food => {
+                // Increase the snake size and create a new food tile.
+                debug!("food eaten at ({}, {})", x, y);
+                self.events.push(GameEvent::Ate(x as usize, y as usize));
+                self.food.retain(|&food| food != (x as usize, y as usize));
+                self.food_spawn_ticks.remove(&(x as usize, y as usize));
+                self.score += self.next_food_value();
+                self.foods_eaten += 1;
+                self.last_food_tick = self.ticks;
+                self.grow(self.growth);
+
+                // The board is full, once the last food tile is eaten and there is no room left.
+                if fills_board {
+                    self.end(GameOverReason::BoardFull);
+                    return MoveOutcome::Ate;
+                }
+
+                // Replace the food, unless there is more than configured, e.g. placed by hand.
+                if self.food.len() < self.food_count {
+                    self.create_food();
+                }
+                if self.obstacle_on_eat {
+                    self.spawn_obstacle();
+                }
+                if self.food.is_empty() && self.map.count(map::Tile::Empty) == 0 {
+                    self.end(GameOverReason::BoardFull);
+                }
+                MoveOutcome::Ate
+            }
+            _ => MoveOutcome::Moved,
+        }
+    }
+
+    /// The location the snake's head will end up at after the next move in the given direction,
+    /// passing through any [one-way](map::Tile::OneWay) tiles facing that direction.
+    fn destination(&self, dir: snake::Direction) -> (isize, isize) {
+        let (mut x, mut y) = self.step(self.snake.x(), self.snake.y(), dir);
+
+        // A row of one-way tiles could be wrapped around endlessly.
+        for _ in 0..W.max(H) {
+            if self.tile_at(x, y) != Some(map::Tile::OneWay(dir)) {
+                break;
+            }
+
+            (x, y) = self.step(x, y, dir);
+        }
+
+        (x, y)
+    }
+
+    /// Returns if moving in the given direction on the next [move](Game::move_snake) wouldn't end
+    /// the game.
+    ///
+    /// The location the tail vacates during the move is considered safe, while turning rules
+    /// are ignored, see [Game::safe_directions]. While the snake is growing, the tail stays in
+    /// place.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder()
+    ///     .initial_size(4)
+    ///     .growth(2)
+    ///     .food_count(0)
+    ///     .build()
+    ///     .unwrap();
+    /// for dir in [Direction::Up, Direction::Left] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    /// game.set_tile(4, 5, Tile::Food);
+    /// game.turn_snake(Direction::Down);
+    /// game.move_snake();
+    ///
+    /// // The tail at (5, 5) doesn't move, after eating the food.
+    /// assert!(!game.is_safe(Direction::Right));
+    ///
+    /// // Without growing, it does.
+    /// let mut game = Game::<10, 10>::builder().initial_size(4).food_count(0).build().unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    /// assert!(game.is_safe(Direction::Right));
+    ///
+    /// game.queue_growth(1);
+    /// assert!(!game.is_safe(Direction::Right));
+    /// ```
+    pub fn is_safe(&self, dir: snake::Direction) -> bool {
+        if self.is_invincible() {
+            return true;
+        }
+
+        let (x, y) = self.destination(dir);
+        match self.tile_at(x, y) {
+            // Crossing a wrapping edge only leaves the map if it is empty.
+            None => self.edge_behavior(x, y) == Some(WallBehavior::Bounce),
+            Some(map::Tile::OneWay(_) | map::Tile::Wall) => false,
+            Some(map::Tile::Snake) => self.vacated_next() == Some((x as usize, y as usize)),
+            Some(_) => true,
+        }
+    }
+
+    /// Returns the directions the snake can [turn](Game::turn_snake) to, which are
+    /// [safe](Game::is_safe) for the next move.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    /// game.turn_snake(Direction::Left);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // In the top left corner, only going down is safe.
+    /// assert_eq!(game.snake_head(), (0, 0));
+    /// assert_eq!(game.safe_directions(), vec![Direction::Down]);
+    /// ```
+    pub fn safe_directions(&self) -> Vec<snake::Direction> {
+        let current = self.snake.direction();
+
+        snake::Direction::ALL
+            .into_iter()
+            .filter(|dir| !current.opposite(*dir) && self.is_safe(*dir))
+            .collect()
+    }
+
+    /// Returns if there are no [safe directions](Game::safe_directions) left, so the next move
+    /// ends the game.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile};
+    /// let mut game = Game::<10, 10>::new();
+    /// assert!(!game.is_stuck());
+    ///
+    /// for (x, y) in [(4, 5), (6, 5), (5, 4), (5, 6)] {
+    ///     game.set_tile(x, y, Tile::Wall);
+    /// }
+    /// assert!(game.is_stuck());
+    /// ```
+    pub fn is_stuck(&self) -> bool {
+        self.safe_directions().is_empty()
+    }
+
+    /// Returns the locations which end the game if the snake moves onto them next, e.g. to shade
+    /// them in an assist overlay.
+    ///
+    /// These are the [snake](map::Tile::Snake) tiles, including AI snakes, and the
+    /// [walls](map::Tile::Wall). Like for [Game::is_safe], the location the tail vacates during
+    /// the next move isn't lethal. [One-way](map::Tile::OneWay) tiles aren't included either,
+    /// since they can be passed in their direction. Invincibility is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().initial_size(4).food_count(0).build().unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    /// game.set_tile(2, 2, Tile::Wall);
+    /// game.set_tile(7, 7, Tile::Food);
+    ///
+    /// // The tail at (5, 5) moves out of the way.
+    /// let lethal = game.lethal_cells();
+    /// assert_eq!(lethal, vec![(2, 2), (4, 4), (4, 5), (5, 4)]);
+    ///
+    /// let (x, y) = game.snake_head();
+    /// for dir in game.safe_directions() {
+    ///     let next = ((x + dir.x()) as usize, (y + dir.y()) as usize);
+    ///     assert!(!lethal.contains(&next));
+    /// }
+    /// ```
+    pub fn lethal_cells(&self) -> Vec<(usize, usize)> {
+        let vacated = self.vacated_next();
+
+        (0..W)
+            .flat_map(|x| (0..H).map(move |y| (x, y)))
+            .filter(|&(x, y)| match self.map.get(x, y) {
+                map::Tile::Snake => vacated != Some((x, y)),
+                map::Tile::Wall => true,
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Returns how dangerous the snake's position is, e.g. to show a warning color.
+    ///
+    /// This depends on the number of [safe](Game::is_safe) moves, the escapes, among those the
+    /// snake can [turn](Game::turn_snake) to:
+    /// - `0`: three or more escapes, so every move is safe for a moving snake, which can't turn
+    ///   around,
+    /// - `1`: two escapes, so one move is lethal,
+    /// - `3`: only one escape is left.
+    ///
+    /// `2` is skipped, so the last escape stands out. A snake without any escape is at `3` as
+    /// well, use [Game::is_stuck] to tell it apart.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert_eq!(game.danger_level(), 0);
+    ///
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// // At the top, going up is lethal.
+    /// assert_eq!(game.danger_level(), 1);
+    ///
+    /// game.turn_snake(Direction::Left);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    /// // In the top left corner, only going down is safe.
+    /// assert_eq!(game.danger_level(), 3);
+    /// assert!(!game.is_stuck());
+    ///
+    /// // A snake which hasn't moved yet can go in all four directions.
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(4, 5, Tile::Wall);
+    /// assert_eq!(game.danger_level(), 0);
+    /// game.set_tile(6, 5, Tile::Wall);
+    /// game.set_tile(5, 4, Tile::Wall);
+    /// assert_eq!(game.danger_level(), 3);
+    /// game.set_tile(5, 6, Tile::Wall);
+    /// assert_eq!(game.danger_level(), 3);
+    /// assert!(game.is_stuck());
+    /// ```
+    pub fn danger_level(&self) -> u8 {
+        match self.safe_directions().len() {
+            0 | 1 => 3,
+            2 => 1,
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of free tiles the snake could reach after moving in the given direction,
+    /// including the one it moves to.
+    ///
+    /// Empty and food tiles are free, as well as the location the tail vacates during the move.
+    /// Returns `0` if the move leaves the map or runs into an obstacle. Callers can use this to
+    /// avoid moves trapping the snake in a region too small to survive.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// for y in 0..10 {
+    ///     game.set_tile(6, y, Tile::Wall);
+    /// }
+    /// for (x, y) in [(4, 4), (4, 6), (3, 5)] {
+    ///     game.set_tile(x, y, Tile::Wall);
+    /// }
+    ///
+    /// // The left of the map, without the head, the walls and the pocket to the left of the head.
+    /// assert_eq!(game.reachable_space(Direction::Up), 6 * 10 - 5);
+    /// // The pocket is a dead end.
+    /// assert_eq!(game.reachable_space(Direction::Left), 1);
+    /// assert_eq!(game.reachable_space(Direction::Right), 0);
+    /// ```
+    pub fn reachable_space(&self, dir: snake::Direction) -> usize {
+        let free = |x, y| self.is_free(x, y);
+
+        let (x, y) = self.destination(dir);
+        if self.tile_at(x, y).is_none() || !free(x as usize, y as usize) {
+            return 0;
+        }
+
+        // Flood fill the free tiles, starting at the destination.
+        let mut visited = vec![false; W * H];
+        let mut stack = vec![(x as usize, y as usize)];
+        visited[x as usize * H + y as usize] = true;
+        let mut count = 0;
+
+        while let Some((x, y)) = stack.pop() {
+            count += 1;
+
+            for (nx, ny) in self.neighbors(x, y) {
+                if !visited[nx * H + ny] && free(nx, ny) {
+                    visited[nx * H + ny] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Returns the cells of the shortest path from the snake's head to the nearest food tile, e.g.
+    /// to highlight them for beginners, or `None` if there is no path.
+    ///
+    /// The path starts next to the head and ends on the food tile. Like [Game::reachable_space],
+    /// it only passes through free tiles.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// assert_eq!(game.hint_path(), None);
+    ///
+    /// game.set_tile(7, 3, Tile::Food);
+    /// let path = game.hint_path().unwrap();
+    /// assert_eq!(path.len(), 4);
+    /// assert_eq!(path.last(), Some(&(7, 3)));
+    ///
+    /// // Every cell is adjacent to the previous one.
+    /// let mut previous = (5, 5);
+    /// for &cell in &path {
+    ///     assert!(Direction::between(previous, cell).is_some());
+    ///     previous = cell;
+    /// }
+    /// ```
+    pub fn hint_path(&self) -> Option<Vec<(usize, usize)>> {
+        if !self.snake.in_bounds(&self.map) {
+            return None;
+        }
+        let head = (self.snake.x() as usize, self.snake.y() as usize);
+
+        // Search breadth first, remembering where every location was reached from.
+        let mut previous = vec![None; W * H];
+        let mut queue = std::collections::VecDeque::from([head]);
+        previous[head.0 * H + head.1] = Some(head);
+
+        while let Some((x, y)) = queue.pop_front() {
+            if self.map.get(x, y) == map::Tile::Food {
+                let mut path = Vec::new();
+                let mut cell = (x, y);
+                while cell != head {
+                    path.push(cell);
+                    cell = previous[cell.0 * H + cell.1].unwrap_or(head);
+                }
+                path.reverse();
+
+                return Some(path);
+            }
+
+            for (nx, ny) in self.neighbors(x, y) {
+                if previous[nx * H + ny].is_none() && self.is_free(nx, ny) {
+                    previous[nx * H + ny] = Some((x, y));
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of moves from the snake's head to every location, indexed as
+    /// `field[x][y]`, or `None` for locations the snake can't reach.
+    ///
+    /// Like [Game::hint_path], the search only passes through free tiles and wraps around the map
+    /// if the [WallBehavior] says so. The head itself is at distance `0`. If the head is out of
+    /// bounds, every location is unreachable.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, WallBehavior};
+    /// let mut game = Game::<3, 3>::builder().initial_size(1).food_count(0).build().unwrap();
+    /// game.set_tile(1, 0, Tile::Wall);
+    /// game.set_tile(2, 1, Tile::Wall);
+    ///
+    /// // The head is in the center, the corner (2, 0) is walled off.
+    /// let field = game.distance_field();
+    /// assert_eq!(field[1][1], Some(0));
+    /// assert_eq!(field[1][0], None);
+    /// assert_eq!(field[0][0], Some(2));
+    /// assert_eq!(field[2][2], Some(2));
+    /// assert_eq!(field[2][0], None);
+    ///
+    /// // Across the boundaries, the corner is reachable.
+    /// game.set_wall_behavior(WallBehavior::Wrap);
+    /// assert_eq!(game.distance_field()[2][0], Some(3));
+    /// ```
+    pub fn distance_field(&self) -> Vec<Vec<Option<usize>>> {
+        let mut field = vec![vec![None; H]; W];
+        if !self.snake.in_bounds(&self.map) {
+            return field;
+        }
+        let head = (self.snake.x() as usize, self.snake.y() as usize);
+
+        let mut queue = std::collections::VecDeque::from([head]);
+        field[head.0][head.1] = Some(0);
+
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = field[x][y].unwrap_or(0) + 1;
+
+            for (nx, ny) in self.neighbors(x, y) {
+                if field[nx][ny].is_none() && self.is_free(nx, ny) {
+                    field[nx][ny] = Some(distance);
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+
+        field
+    }
+
+    /// Returns if the snake could pass through location `(x,y)` on its next move.
+    ///
+    /// Empty and food tiles are free, as well as the location the tail vacates during the move.
+    fn is_free(&self, x: usize, y: usize) -> bool {
+        matches!(self.map.get(x, y), map::Tile::Empty | map::Tile::Food)
+            || self.vacated_next() == Some((x, y))
+    }
+
+    /// Returns the location the tail vacates during the next move, unless the snake grows by a
+    /// [queued](Game::queue_growth) segment, keeping the tail in place.
+    fn vacated_next(&self) -> Option<(usize, usize)> {
+        self.snake
+            .vacated_next()
+            .filter(|_| self.pending_growth == 0)
+    }
+
+    /// Iterates over the locations adjacent to `(x,y)`, which the snake could move to next.
+    ///
+    /// Unlike [map::Map::neighbors], these include the locations across the boundaries if the
+    /// snake [wraps](WallBehavior::Wrap) around the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, WallBehavior};
+    /// let mut game = Game::<10, 10>::new();
+    /// assert_eq!(game.neighbors(0, 0).count(), 2);
+    /// assert_eq!(game.neighbors(5, 5).count(), 4);
+    ///
+    /// game.set_wall_behavior(WallBehavior::Wrap);
+    /// assert_eq!(
+    ///     game.neighbors(0, 0).collect::<Vec<_>>(),
+    ///     vec![(9, 0), (1, 0), (0, 9), (0, 1)]
+    /// );
+    /// ```
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        snake::Direction::ALL
+            .into_iter()
+            .map(move |dir| self.step(x as isize, y as isize, dir))
+            .filter(|&(x, y)| self.tile_at(x, y).is_some())
+            .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    /// Returns the location after moving from `(x,y)` in the given direction, wrapping around the
+    /// map if the [BorderConfig] says so for the crossed edge.
+    fn step(&self, x: isize, y: isize, dir: snake::Direction) -> (isize, isize) {
+        self.step_by(x, y, dir.x(), dir.y())
+    }
+
+    /// Like [Game::step], but moving by the given change on the x- and y-axis.
+    fn step_by(&self, x: isize, y: isize, dx: isize, dy: isize) -> (isize, isize) {
+        let (mut x, mut y) = (x + dx, y + dy);
+        // There is nothing to wrap around to on an empty map.
+        if W == 0 || H == 0 {
+            return (x, y);
+        }
+        let (wrapped_x, wrapped_y) = self.map.wrap_coords(x, y);
+        let wraps = |behavior| behavior == WallBehavior::Wrap;
+
+        if (x < 0 && wraps(self.border.left)) || (x >= W as isize && wraps(self.border.right)) {
+            x = wrapped_x as isize;
+        }
+        if (y < 0 && wraps(self.border.top)) || (y >= H as isize && wraps(self.border.bottom)) {
+            y = wrapped_y as isize;
+        }
+
+        (x, y)
+    }
+
+    /// Returns the behavior of the edge the snake crossed to reach `(x,y)`, or `None` if the
+    /// location is in bounds.
+    ///
+    /// Locations beyond a corner belong to the left or right edge.
+    fn edge_behavior(&self, x: isize, y: isize) -> Option<WallBehavior> {
+        if x < 0 {
+            Some(self.border.left)
+        } else if x >= W as isize {
+            Some(self.border.right)
+        } else if y < 0 {
+            Some(self.border.top)
+        } else if y >= H as isize {
+            Some(self.border.bottom)
+        } else {
+            None
+        }
+    }
+
+    /// Returns if the snake can cross the left or right edge, and the top or bottom edge.
+    fn wraps(&self) -> (bool, bool) {
+        let border = self.border;
+        (
+            border.left == WallBehavior::Wrap || border.right == WallBehavior::Wrap,
+            border.top == WallBehavior::Wrap || border.bottom == WallBehavior::Wrap,
+        )
+    }
+
+    /// Returns the [Tile](map::Tile) at location `(x,y)` or `None` if it is out of bounds.
+    fn tile_at(&self, x: isize, y: isize) -> Option<map::Tile> {
+        if 0 <= x && 0 <= y && self.map.in_bounds(x as usize, y as usize) {
+            Some(self.map.get(x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    /// Sets what happens when the snake tries to leave the map.
+    ///
+    /// When bouncing, the snake [reverses](snake::Snake::reverse) in place: its oldest tail piece
+    /// becomes the head, which then continues the tail's path backwards on the following moves.
+    /// Bouncing takes up the whole move, so the snake doesn't advance on the move it hits the
+    /// boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction, State, WallBehavior};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.set_wall_behavior(WallBehavior::Bounce);
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_head(), (5, 0));
+    ///
+    /// // Hit the top edge and move down afterwards.
+    /// game.move_snake();
+    /// assert!(game.state != State::GameOver);
+    /// assert_eq!(game.snake_direction(), Direction::Down);
+    ///
+    /// let (_, y) = game.snake_head();
+    /// game.move_snake();
+    /// assert_eq!(game.snake_head().1, y + 1);
+    /// ```
+    /// ```
+    /// use rust_snake::{Game, snake::Direction, WallBehavior};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.set_wall_behavior(WallBehavior::Wrap);
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..6 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_head(), (5, 9));
+    /// ```
+    pub fn set_wall_behavior(&mut self, behavior: WallBehavior) {
+        self.border = BorderConfig::uniform(behavior);
+    }
+
+    /// Returns what happens when the snake tries to leave the map.
+    ///
+    /// If the edges behave differently, this is the behavior of the left edge, see
+    /// [Game::border_config].
+    pub fn wall_behavior(&self) -> WallBehavior {
+        self.border.left
+    }
+
+    /// Sets what happens when the snake leaves the map across each of its edges, like
+    /// [Game::set_wall_behavior] does for all of them.
+    ///
+    /// Wrapping across an edge re-enters the map from the opposite edge, even if that one
+    /// behaves differently.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{BorderConfig, Game, snake::Direction, State, WallBehavior};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_border_config(BorderConfig {
+    ///     left: WallBehavior::Wrap,
+    ///     bottom: WallBehavior::Bounce,
+    ///     ..BorderConfig::uniform(WallBehavior::Die)
+    /// });
+    ///
+    /// // The left edge wraps around to the right.
+    /// game.turn_snake(Direction::Left);
+    /// for _ in 0..6 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_head(), (9, 5));
+    ///
+    /// // The bottom edge turns the snake around.
+    /// game.turn_snake(Direction::Down);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_direction(), Direction::Up);
+    ///
+    /// // The right edge is lethal.
+    /// game.turn_snake(Direction::Right);
+    /// game.move_snake();
+    /// assert!(game.state == State::GameOver);
+    /// ```
+    pub fn set_border_config(&mut self, config: BorderConfig) {
+        self.border = config;
+    }
+
+    /// Returns what happens when the snake leaves the map across each of its edges.
+    ///
+    /// Renderers can use this to draw the edges according to their behavior.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{BorderConfig, Game, WallBehavior};
+    /// let config = BorderConfig {
+    ///     left: WallBehavior::Wrap,
+    ///     right: WallBehavior::Wrap,
+    ///     top: WallBehavior::Bounce,
+    ///     bottom: WallBehavior::Die,
+    /// };
+    /// let game = Game::<10, 10>::builder().border_config(config).build().unwrap();
+    /// assert_eq!(game.border_config(), config);
+    /// assert_eq!(game.border_config().top, WallBehavior::Bounce);
+    ///
+    /// let game = Game::<10, 10>::builder().wall_behavior(WallBehavior::Bounce).build().unwrap();
+    /// assert_eq!(game.border_config(), BorderConfig::uniform(WallBehavior::Bounce));
+    /// ```
+    pub fn border_config(&self) -> BorderConfig {
+        self.border
+    }
+
+    /// Sets how the directions passed to [Game::turn_snake] are remapped.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{ControlTransform, Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.set_control_transform(ControlTransform::RotateCw);
+    ///
+    /// game.turn_snake(Direction::Left);
+    /// assert_eq!(game.snake_direction(), Direction::Up);
+    ///
+    /// // Turning around is still refused.
+    /// game.turn_snake(Direction::Right);
+    /// assert_eq!(game.snake_direction(), Direction::Up);
+    /// ```
+    pub fn set_control_transform(&mut self, transform: ControlTransform) {
+        self.control_transform = transform;
+    }
+
+    /// Returns how the directions passed to [Game::turn_snake] are remapped.
+    pub fn control_transform(&self) -> ControlTransform {
+        self.control_transform
+    }
+
+    /// Returns the [MoveOutcome] of the last [move](Game::move_snake), or [Idle](MoveOutcome::Idle)
+    /// if the snake hasn't moved since the game was created or [restarted](Game::restart).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, MoveOutcome, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(5, 3, Tile::Food);
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Idle);
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Moved);
+    /// game.move_snake();
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Ate);
+    ///
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.last_outcome(), MoveOutcome::Died);
+    /// ```
+    pub fn last_outcome(&self) -> MoveOutcome {
+        self.last_outcome
+    }
+
+    /// Returns if the snake ate a food tile during the last [move](Game::move_snake), e.g. to play
+    /// a sound, see [Game::last_outcome].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(5, 4, Tile::Food);
+    /// game.turn_snake(Direction::Up);
+    ///
+    /// game.move_snake();
+    /// assert!(game.just_ate());
+    /// game.move_snake();
+    /// assert!(!game.just_ate());
+    /// ```
+    pub fn just_ate(&self) -> bool {
+        self.last_outcome == MoveOutcome::Ate
+    }
+
+    /// Returns the number of [moves](Game::move_snake) since the game was created or
+    /// [restarted](Game::restart).
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the number of [ticks](Game::ticks) left until the configured
+    /// [tick limit](builder::GameBuilder::tick_limit) ends the game or `None` if there is none.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().tick_limit(3).build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// assert_eq!(game.ticks_remaining(), Some(3));
+    ///
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_remaining(), Some(1));
+    /// assert_eq!(game.game_over_reason(), None);
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_remaining(), Some(0));
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::TimeUp));
+    /// ```
+    pub fn ticks_remaining(&self) -> Option<u64> {
+        self.tick_limit
+            .map(|limit| limit.saturating_sub(self.ticks))
+    }
+
+    /// Returns the number of [ticks](Game::ticks) until the snake starves by one size or `None` if
+    /// the snake doesn't get hungry, see
+    /// [GameBuilder::hunger_interval](builder::GameBuilder::hunger_interval).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder()
+    ///     .hunger_interval(2)
+    ///     .initial_size(1)
+    ///     .food_count(0)
+    ///     .build()
+    ///     .unwrap();
+    /// game.set_tile(5, 3, Tile::Food);
+    /// game.turn_snake(Direction::Up);
+    /// assert_eq!(game.ticks_until_hunger(), Some(2));
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_until_hunger(), Some(1));
+    ///
+    /// // Eating resets the timer.
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_until_hunger(), Some(2));
+    ///
+    /// // The snake starves twice, until its size reaches zero.
+    /// game.turn_snake(Direction::Left);
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::Shrunk));
+    /// ```
+    pub fn ticks_until_hunger(&self) -> Option<u64> {
+        self.hunger_interval
+            .map(|interval| interval.saturating_sub(self.hunger))
+    }
+
+    /// Returns the number of [ticks](Game::ticks) since the snake last ate, or since the game was
+    /// created or [restarted](Game::restart) if it hasn't eaten yet.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(5, 2, Tile::Food);
+    /// game.turn_snake(Direction::Up);
+    ///
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_since_food(), 2);
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_since_food(), 0);
+    /// game.move_snake();
+    /// assert_eq!(game.ticks_since_food(), 1);
+    /// ```
+    pub fn ticks_since_food(&self) -> u64 {
+        self.ticks - self.last_food_tick
+    }
+
+    /// Returns the total [value](Game::next_food_value) of the food tiles the snake ate since the
+    /// game was created or [restarted](Game::restart).
+    pub fn score(&self) -> usize {
+        self.score
+    }
+
+    /// Computes the [score](Game::score) from the number of food tiles eaten, according to the
+    /// configured scheme.
+    ///
+    /// The snake eats the first food tile at its initial size and grows by the configured
+    /// [growth](builder::GameBuilder::growth) after every one, up to the
+    /// [maximum size](builder::GameBuilder::max_size). The score is the sum of the
+    /// [food value](builder::GameBuilder::food_value) of each of these sizes, minus one point for
+    /// every [boost](Game::request_boost).
+    ///
+    /// This differs from the score, if the snake's size changed other than by eating, e.g. by
+    /// [shrinking](Game::shrink_snake), [queued growth](Game::queue_growth) or losing a
+    /// [life](Game::lives).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder()
+    ///     .food_count(0)
+    ///     .food_value(|size| size)
+    ///     .build()
+    ///     .unwrap();
+    /// for y in 2..5 {
+    ///     game.set_tile(5, y, Tile::Food);
+    /// }
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..4 {
+    ///     game.move_snake();
+    /// }
+    /// assert!(game.request_boost());
+    ///
+    /// // Eaten at the sizes 3, 4 and 5, with one boost.
+    /// assert_eq!(game.compute_score(), 3 + 4 + 5 - 1);
+    /// assert_eq!(game.compute_score(), game.score());
+    ///
+    /// // The next food tile is eaten at size 5 instead of 6.
+    /// game.shrink_snake(1);
+    /// game.set_tile(5, 0, Tile::Food);
+    /// game.move_snake();
+    /// assert_eq!(game.score(), 3 + 4 + 5 - 1 + 5);
+    /// assert_eq!(game.compute_score(), 3 + 4 + 5 - 1 + 6);
+    /// ```
+    pub fn compute_score(&self) -> usize {
+        let max = self
+            .max_size
+            .map_or(usize::MAX, |max| max.max(self.initial_size));
+        let eaten: usize = (0..self.foods_eaten)
+            .map(|meal| (self.initial_size + meal * self.growth).min(max))
+            .map(self.food_value)
+            .sum();
+        eaten.saturating_sub(self.boosts * BOOST_COST)
+    }
+
+    /// Returns by how much the [score](Game::score) increases when the snake eats the next food
+    /// tile.
+    ///
+    /// By default food is worth one point plus one for every ten sizes of the snake, see
+    /// [GameBuilder::food_value](builder::GameBuilder::food_value).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::Game;
+    /// let game = Game::<10, 10>::new();
+    /// assert_eq!(game.next_food_value(), 1);
+    ///
+    /// let game = Game::<10, 10>::builder().initial_size(25).build().unwrap();
+    /// assert_eq!(game.next_food_value(), 3);
+    ///
+    /// let game = Game::<10, 10>::builder()
+    ///     .initial_size(25)
+    ///     .food_value(|size| size * size)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(game.next_food_value(), 625);
+    /// ```
+    pub fn next_food_value(&self) -> usize {
+        (self.food_value)(self.snake.size)
+    }
+
+    /// Returns the [score](Game::score) of a perfect game, in which the snake eats food until it
+    /// fills the whole map, without any [boosts](Game::request_boost).
+    ///
+    /// This only depends on the size of the map and the configured initial size,
+    /// [growth](builder::GameBuilder::growth) and
+    /// [food value](builder::GameBuilder::food_value). Returns `None` if the snake can't fill the
+    /// map, since it doesn't grow or its [size is capped](builder::GameBuilder::max_size) below
+    /// that, so the score has no limit.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::Game;
+    /// let game = Game::<3, 2>::builder()
+    ///     .initial_size(2)
+    ///     .food_value(|size| size * 10)
+    ///     .build()
+    ///     .unwrap();
+    /// // Eaten at the sizes 2, 3, 4 and 5.
+    /// assert_eq!(game.max_possible_score(), Some(20 + 30 + 40 + 50));
+    ///
+    /// let game = Game::<3, 2>::builder().initial_size(2).growth(3).build().unwrap();
+    /// assert_eq!(game.max_possible_score(), Some(2));
+    ///
+    /// let game = Game::<3, 2>::builder().max_size(4).build().unwrap();
+    /// assert_eq!(game.max_possible_score(), None);
+    /// ```
+    pub fn max_possible_score(&self) -> Option<usize> {
+        let area = W * H;
+        if self.growth == 0 || self.max_size.is_some_and(|max| max < area) {
+            return None;
+        }
+
+        Some(
+            (self.initial_size..area)
+                .step_by(self.growth)
+                .map(self.food_value)
+                .sum(),
+        )
+    }
+
+    /// Returns the suggested delay between two [moves](Game::move_snake), in milliseconds.
+    ///
+    /// The delay starts at 100ms and drops by one millisecond for every size the snake grew or
+    /// every point of score, depending on the configured [SpeedBasis], down to 40ms. While
+    /// [boosted](Game::request_boost), the delay is halved.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction, SpeedBasis};
+    /// let mut game = Game::<10, 10>::builder()
+    ///     .speed_basis(SpeedBasis::Score)
+    ///     .food_value(|_| 20)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(game.suggested_delay_ms(), 100);
+    ///
+    /// game.set_tile(5, 4, Tile::Food);
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert_eq!(game.score(), 20);
+    /// assert_eq!(game.suggested_delay_ms(), 80);
+    /// ```
+    pub fn suggested_delay_ms(&self) -> u64 {
+        let growth = self.snake.size.saturating_sub(self.initial_size) as u64;
+        let speedup = match self.speed_basis {
+            SpeedBasis::Size => growth,
+            SpeedBasis::Score => self.score as u64,
+            SpeedBasis::Both => growth + self.score as u64,
+        };
+        let delay = BASE_DELAY_MS.saturating_sub(speedup).max(MIN_DELAY_MS);
+
+        if self.is_boosted() {
+            delay / 2
+        } else {
+            delay
+        }
+    }
+
+    /// Returns what the [suggested delay](Game::suggested_delay_ms) is based on.
+    pub fn speed_basis(&self) -> SpeedBasis {
+        self.speed_basis
+    }
+
+    /// Boosts the snake for the next ten moves, halving the
+    /// [suggested delay](Game::suggested_delay_ms).
+    ///
+    /// The boost costs one point of [score](Game::score), trading score for speed. Returns `false`
+    /// and doesn't boost the snake, if the score is too low or the snake is already boosted.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// assert!(!game.request_boost());
+    ///
+    /// game.set_tile(5, 4, Tile::Food);
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// let delay = game.suggested_delay_ms();
+    ///
+    /// assert!(game.request_boost());
+    /// assert_eq!(game.score(), 0);
+    /// assert_eq!(game.boost_ticks(), 10);
+    /// assert!(game.suggested_delay_ms() < delay);
+    /// ```
+    pub fn request_boost(&mut self) -> bool {
+        if self.is_boosted() || self.score < BOOST_COST {
+            return false;
+        }
+
+        self.score -= BOOST_COST;
+        self.boosts += 1;
+        self.boost_ticks = BOOST_TICKS;
+        true
+    }
+
+    /// Returns the number of moves the current [boost](Game::request_boost) lasts.
+    pub fn boost_ticks(&self) -> u32 {
+        self.boost_ticks
+    }
+
+    /// Returns if the snake is currently [boosted](Game::request_boost).
+    pub fn is_boosted(&self) -> bool {
+        self.boost_ticks > 0
+    }
+
+    /// Makes the snake invincible for the given number of [moves](Game::move_snake).
+    ///
+    /// Invincibility doesn't stack, the remaining ticks are set to the larger of the current and
+    /// the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake, State};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.turn_snake(snake::Direction::Up);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // The snake is at the top edge and survives bumping into it twice.
+    /// game.make_invincible(2);
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert!(game.state != State::GameOver);
+    /// assert!(!game.is_invincible());
+    ///
+    /// game.move_snake();
+    /// assert!(game.state == State::GameOver);
+    /// ```
+    pub fn make_invincible(&mut self, ticks: u32) {
+        self.invincible_ticks = self.invincible_ticks.max(ticks);
+    }
+
+    /// Returns if collisions are currently ignored, see [Game::make_invincible].
+    ///
+    /// The snake is always invincible in [sandbox](builder::GameBuilder::sandbox) games.
+    pub fn is_invincible(&self) -> bool {
+        self.sandbox || self.invincible_ticks > 0
+    }
+
+    /// Returns if this is a [sandbox](builder::GameBuilder::sandbox) game.
+    pub fn is_sandbox(&self) -> bool {
+        self.sandbox
+    }
+
+    /// The game's random number generator, for extensions needing randomness, e.g. custom food
+    /// placement or AI decisions.
+    ///
+    /// Drawing from it affects the following food placement, but
+    /// [seeded](builder::GameBuilder::seed) games stay reproducible as long as the draws happen the
+    /// same way. Using any other source of randomness, like [rand::thread_rng], breaks that, e.g.
+    /// for [replays](replay::Replay).
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::Rng;
+    /// use rust_snake::Game;
+    /// let mut game = Game::<10, 10>::builder().seed(1).build().unwrap();
+    /// let mut other = Game::<10, 10>::builder().seed(1).build().unwrap();
+    /// assert_eq!(game.rng().gen::<u32>(), other.rng().gen::<u32>());
+    /// ```
+    pub fn rng(&mut self) -> &mut impl Rng {
+        &mut self.rng
+    }
+
+    /// Create a food tile on a random, previously unoccupied space.
+    ///
+    /// Every applicable space is equally likely. Since exactly one random number is drawn per food
+    /// tile, [seeded](builder::GameBuilder::seed) games place their food reproducibly.
+    ///
+    /// With [GameBuilder::food_under_tail](builder::GameBuilder::food_under_tail), the space the
+    /// tail vacates on the next move is applicable as well, unless the snake's head is on it or
+    /// about to move onto it. Spaces rejected by the configured
+    /// [food filter](builder::GameBuilder::food_filter) are skipped. Returns `false` if there is
+    /// no applicable space left. Spaces closer to the snake's head than the
+    /// [minimum distance](builder::GameBuilder::min_food_distance) are only used if there is no
+    /// other applicable space.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, snake::Direction};
+    /// let mut game = Game::<2, 1>::new();
+    /// assert!(!game.create_food());
+    ///
+    /// // Eating the last food tile fills the board.
+    /// game.turn_snake(Direction::Left);
+    /// game.move_snake();
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::BoardFull));
+    /// ```
+    pub fn create_food(&mut self) -> bool {
+        if !self.food_enabled {
+            return false;
+        }
+
+        // Choose uniformly among every applicable location, using a single random number.
+        let applicable = |x, y| {
+            self.food_filter
+                .is_none_or(|filter| filter(x, y, &self.map))
+        };
+        let mut candidates: Vec<_> = (0..W)
+            .flat_map(|x| (0..H).map(move |y| (x, y)))
+            .filter(|&(x, y)| self.map.get(x, y) == map::Tile::Empty && applicable(x, y))
+            .collect();
+
+        // The tail only moves out of the way for sure, if the snake doesn't grow. The head mustn't
+        // be on the location or enter it next, so the food isn't eaten while the tail is on it.
+        if self.food_under_tail && self.pending_growth == 0 {
+            let head = (self.snake.x(), self.snake.y());
+            let next = match self.heading {
+                Some(heading) => self.step_by(head.0, head.1, heading.x(), heading.y()),
+                None => self.destination(self.snake.direction()),
+            };
+            candidates.extend(self.snake.vacated_next().filter(|&(x, y)| {
+                let at = (x as isize, y as isize);
+                at != head && at != next && !self.food.contains(&(x, y)) && applicable(x, y)
+            }));
+        }
+        if candidates.is_empty() {
+            return false;
+        }
+
+        // Keep away from the head, unless there is no room for that.
+        let distant: Vec<_> = candidates
+            .iter()
+            .copied()
+            .filter(|&(x, y)| self.distance_from_head(x, y) >= self.min_food_distance)
+            .collect();
+        let candidates = if distant.is_empty() {
+            candidates
+        } else {
+            distant
+        };
+
+        let (fx, fy) = candidates[self.rng.gen_range(0..candidates.len())];
+        if !self.snake.occupies(fx, fy) {
+            self.map.set(fx, fy, map::Tile::Food);
+        }
+        self.food.push((fx, fy));
+        self.food_spawn_ticks.insert((fx, fy), self.ticks);
+        trace!("food spawned at ({}, {})", fx, fy);
+        true
+    }
+
+    /// Places the food tiles created under the snake's tail, once the tail moved out of the way.
+    fn reveal_food(&mut self) {
+        for &(x, y) in &self.food {
+            if self.map.get(x, y) == map::Tile::Empty {
+                self.map.set(x, y, map::Tile::Food);
+            }
+        }
+    }
+
+    /// Turns a random empty tile into a [wall](map::Tile::Wall), see
+    /// [GameBuilder::obstacle_on_eat](builder::GameBuilder::obstacle_on_eat).
+    ///
+    /// Returns `false` if there is no empty tile left.
+    fn spawn_obstacle(&mut self) -> bool {
+        let candidates: Vec<_> = (0..W)
+            .flat_map(|x| (0..H).map(move |y| (x, y)))
+            .filter(|&(x, y)| self.map.get(x, y) == map::Tile::Empty)
+            .collect();
+        if candidates.is_empty() {
+            return false;
+        }
+
+        let (x, y) = candidates[self.rng.gen_range(0..candidates.len())];
+        self.map.set(x, y, map::Tile::Wall);
+        trace!("obstacle spawned at ({}, {})", x, y);
+        true
+    }
+
+    /// The location of the snake's head.
+    ///
+    /// The head may be out of bounds after the snake left the map.
+    pub fn snake_head(&self) -> (isize, isize) {
+        (self.snake.x(), self.snake.y())
+    }
+
+    /// The [Direction](snake::Direction) the snake is facing.
+    pub fn snake_direction(&self) -> snake::Direction {
+        self.snake.direction()
+    }
+
+    /// Iterates over the locations of the snake, from the oldest tail piece to the head, see
+    /// [snake::Snake::body].
+    pub fn snake_body(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.snake.body()
+    }
+
+    /// The location of the oldest tail piece, which is vacated next once the snake reached its
+    /// size, or `None` if the snake has no tail, see [snake::Snake::tail_end].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// assert_eq!(game.snake_tail(), None);
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert_eq!(game.snake_tail(), Some((5, 5)));
+    /// assert_eq!(game.snake_tail(), game.snake_body().next());
+    /// ```
+    pub fn snake_tail(&self) -> Option<(usize, usize)> {
+        self.snake.tail_end()
+    }
+
+    /// Describes the shape of the snake as the [Direction](snake::Direction)s leading from the
+    /// [head](Game::snake_head) through the body to the oldest tail piece.
+    ///
+    /// Starting at the head and following the directions in order visits every part of the
+    /// snake. Renderers can use this to tell where the body bends.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.turn_snake(Direction::Left);
+    /// game.move_snake();
+    ///
+    /// assert_eq!(game.snake_head(), (4, 4));
+    /// assert_eq!(game.body_directions(), vec![Direction::Right, Direction::Down]);
+    /// ```
+    pub fn body_directions(&self) -> Vec<snake::Direction> {
+        let body: Vec<_> = self.snake_body().collect();
+
+        body.windows(2)
+            .rev()
+            .map(|pair| self.direction_between(pair[1], pair[0]))
+            .collect()
+    }
+
+    /// Returns the parts of the snake from the oldest tail piece to the head, along with the
+    /// directions connecting them.
+    ///
+    /// Renderers can use this to pick straight, corner, head and tail sprites: a part is straight
+    /// if its incoming and outgoing directions are equal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, Segment, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// for dir in [Direction::Up, Direction::Up, Direction::Up, Direction::Left] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// assert_eq!(
+    ///     game.body_segments(),
+    ///     vec![
+    ///         Segment {
+    ///             location: (5, 3),
+    ///             incoming: Direction::None,
+    ///             outgoing: Direction::Up,
+    ///         },
+    ///         Segment {
+    ///             location: (5, 2),
+    ///             incoming: Direction::Up,
+    ///             outgoing: Direction::Left,
+    ///         },
+    ///         Segment {
+    ///             location: (4, 2),
+    ///             incoming: Direction::Left,
+    ///             outgoing: Direction::None,
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    pub fn body_segments(&self) -> Vec<Segment> {
+        let body: Vec<_> = self.snake_body().collect();
+
+        body.iter()
+            .enumerate()
+            .map(|(i, &location)| Segment {
+                location,
+                incoming: match i.checked_sub(1) {
+                    Some(prev) => self.direction_between(body[prev], location),
+                    None => snake::Direction::None,
+                },
+                outgoing: match body.get(i + 1) {
+                    Some(&next) => self.direction_between(location, next),
+                    None => snake::Direction::None,
+                },
+            })
+            .collect()
+    }
+
+    /// Returns the [Direction](snake::Direction) stepping from one location to an adjacent one,
+    /// or `None` if they aren't adjacent.
+    ///
+    /// Unlike [snake::Direction::between], locations across the boundaries are adjacent if the
+    /// snake [wraps](WallBehavior::Wrap) around the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction, WallBehavior};
+    /// let mut game = Game::<10, 10>::new();
+    /// assert_eq!(game.adjacent_direction((0, 3), (9, 3)), None);
+    ///
+    /// game.set_wall_behavior(WallBehavior::Wrap);
+    /// assert_eq!(game.adjacent_direction((0, 3), (9, 3)), Some(Direction::Left));
+    /// assert_eq!(game.adjacent_direction((4, 9), (4, 0)), Some(Direction::Down));
+    /// ```
+    pub fn adjacent_direction(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) -> Option<snake::Direction> {
+        snake::Direction::ALL.into_iter().find(|&dir| {
+            self.step(from.0 as isize, from.1 as isize, dir) == (to.0 as isize, to.1 as isize)
+        })
+    }
+
+    /// Returns the direction leading from one part of the snake to the following one.
+    ///
+    /// The parts don't have to be adjacent, since the snake may have passed through
+    /// [one-way](map::Tile::OneWay) tiles or [wrapped](WallBehavior::Wrap) around the map in
+    /// between.
+    fn direction_between(&self, from: (usize, usize), to: (usize, usize)) -> snake::Direction {
+        let (wraps_x, wraps_y) = self.wraps();
+        let delta = |from: usize, to: usize, size: usize, wrapped: bool| {
+            let delta = (to as isize - from as isize).signum();
+            if wrapped && from.abs_diff(to) == size - 1 && size > 2 {
+                -delta
+            } else {
+                delta
+            }
+        };
+
+        snake::Direction::from_delta(
+            delta(from.0, to.0, W, wraps_x),
+            delta(from.1, to.1, H, wraps_y),
+        )
+        .unwrap_or(snake::Direction::None)
+    }
+
+    /// Returns the bounding box of the snake as `(min_x, min_y, max_x, max_y)`, all inclusive.
+    ///
+    /// This is useful for a viewport following the snake on large maps.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// assert_eq!(game.snake_bounds(), (5, 5, 5, 5));
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.turn_snake(Direction::Left);
+    /// game.move_snake();
+    /// assert_eq!(game.snake_bounds(), (4, 4, 5, 5));
+    ///
+    /// // The head left the map at the bottom.
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Down);
+    /// while !game.is_over() {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_head(), (5, 10));
+    /// assert_eq!(game.snake_bounds(), (5, 8, 5, 9));
+    /// assert!(game.snake().body_view().all(|(_, y)| y < 10));
+    /// assert!(game.reverse_snake().is_err());
+    /// ```
+    pub fn snake_bounds(&self) -> (usize, usize, usize, usize) {
+        self.snake_body().fold(
+            (usize::MAX, usize::MAX, 0, 0),
+            |(min_x, min_y, max_x, max_y), (x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            },
+        )
+    }
+
+    /// Returns if the location `(x,y)` is occupied by the snake, see [snake::Snake::occupies].
+    ///
+    /// Unlike looking the location up on the map, this doesn't require a [display](Game::display)
+    /// call and runs in constant time. It only tells snake locations apart from everything else,
+    /// no matter which tile is drawn there.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake};
+    /// let mut game = Game::<10, 10>::new();
+    /// assert!(game.is_snake(5, 5));
+    ///
+    /// game.turn_snake(snake::Direction::Up);
+    /// game.move_snake();
+    /// // The head, a tail piece and an empty location.
+    /// assert!(game.is_snake(5, 4));
+    /// assert!(game.is_snake(5, 5));
+    /// assert!(!game.is_snake(4, 5));
+    /// ```
+    pub fn is_snake(&self, x: usize, y: usize) -> bool {
+        self.map.in_bounds(x, y) && self.snake.occupies(x, y)
+    }
+
+    /// Same as [Game::is_snake].
+    #[deprecated(note = "use `Game::is_snake` instead")]
+    pub fn snake_occupies(&self, x: usize, y: usize) -> bool {
+        self.is_snake(x, y)
+    }
+
+    /// Returns the Manhattan distance from the snake's head to the nearest food tile or `None` if
+    /// there is no food.
+    ///
+    /// If the snake [wraps](WallBehavior::Wrap) around the map, the shorter distance across the
+    /// boundaries is used.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction, WallBehavior};
+    /// let mut game = Game::<10, 10>::new();
+    /// # let food: Vec<_> = game.display(|map| map.locations(Tile::Food).collect());
+    /// # for (x, y) in food {
+    /// #     game.set_tile(x, y, Tile::Empty);
+    /// # }
+    /// assert_eq!(game.distance_to_food(), None);
+    ///
+    /// game.turn_snake(Direction::Right);
+    /// for _ in 0..3 {
+    ///     game.move_snake();
+    /// }
+    /// game.set_tile(0, 4, Tile::Food);
+    /// assert_eq!(game.distance_to_food(), Some(9));
+    ///
+    /// game.set_wall_behavior(WallBehavior::Wrap);
+    /// assert_eq!(game.distance_to_food(), Some(3));
+    /// ```
+    pub fn distance_to_food(&self) -> Option<usize> {
+        self.food
+            .iter()
+            .map(|&(x, y)| self.distance_from_head(x, y))
+            .min()
+    }
+
+    /// Returns the Manhattan distance from the snake's head to `(x,y)`, across the boundaries if
+    /// that is shorter and the snake [wraps](WallBehavior::Wrap) around the map.
+    fn distance_from_head(&self, x: usize, y: usize) -> usize {
+        self.distance_between(self.snake_head(), (x, y))
+    }
+
+    /// Returns the Manhattan distance between two locations, across the boundaries if that is
+    /// shorter and the snake [wraps](WallBehavior::Wrap) around the map.
+    fn distance_between(&self, (fx, fy): (isize, isize), (x, y): (usize, usize)) -> usize {
+        let dx = fx.abs_diff(x as isize);
+        let dy = fy.abs_diff(y as isize);
+
+        let (wraps_x, wraps_y) = self.wraps();
+        let dx = if wraps_x { dx.min(W.abs_diff(dx)) } else { dx };
+        let dy = if wraps_y { dy.min(H.abs_diff(dy)) } else { dy };
+
+        dx + dy
+    }
+
+    /// Returns if the nearest food tile is within the
+    /// [fog radius](builder::GameBuilder::fog_radius) of the snake's head, measured like
+    /// [Game::distance_to_food].
+    ///
+    /// Without fog, food is always visible. Hiding the food is up to the renderer.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().fog_radius(2).build().unwrap();
+    /// # let food = game.food().to_vec();
+    /// # for (x, y) in food {
+    /// #     game.set_tile(x, y, Tile::Empty);
+    /// # }
+    /// game.set_tile(5, 1, Tile::Food);
+    /// assert!(!game.food_visible());
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert!(!game.food_visible());
+    /// game.move_snake();
+    /// assert!(game.food_visible());
+    /// ```
+    pub fn food_visible(&self) -> bool {
+        match self.fog_radius {
+            Some(radius) => self
+                .distance_to_food()
+                .is_some_and(|distance| distance <= radius),
+            None => true,
+        }
+    }
+
+    /// Sets the [Tile](map::Tile) at location `(x,y)`, e.g. to build a level.
+    ///
+    /// Locations occupied by the snake or an [AI snake](Game::add_ai_snake) and
+    /// [snake tiles](map::Tile::Snake) are left untouched, since they are managed by the game.
+    /// Neither is food placed while it is [disabled](Game::set_food_enabled). Returns if the tile
+    /// was set.
+    ///
+    /// # Panics
+    ///
+    /// If the location is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// assert!(game.add_ai_snake(2, 2));
+    ///
+    /// assert!(!game.set_tile(5, 5, Tile::Wall));
+    /// assert!(!game.set_tile(2, 2, Tile::Wall));
+    /// assert!(game.set_tile(2, 3, Tile::Wall));
+    /// ```
+    pub fn set_tile(&mut self, x: usize, y: usize, tile: map::Tile) -> bool {
+        if tile == map::Tile::Snake
+            || self.is_occupied(x, y)
+            || (tile == map::Tile::Food && !self.food_enabled)
+        {
+            return false;
+        }
+
+        // Keep track of added and removed food.
+        if self.map.get(x, y) == map::Tile::Food {
+            self.food.retain(|&food| food != (x, y));
+            self.food_spawn_ticks.remove(&(x, y));
+        }
+        if tile == map::Tile::Food {
+            self.food.push((x, y));
+            self.food_spawn_ticks.insert((x, y), self.ticks);
+        }
+
+        self.map.set(x, y, tile);
+        true
+    }
+
+    /// Returns if the snake or an [AI snake](Game::add_ai_snake) occupies location `(x,y)`.
+    fn is_occupied(&self, x: usize, y: usize) -> bool {
+        self.snake.occupies(x, y) || self.ai_snakes.iter().any(|ai| ai.occupies(x, y))
+    }
+
+    /// Returns the locations of every food tile, from oldest to newest.
+    ///
+    /// This includes food created under the snake's tail, see
+    /// [GameBuilder::food_under_tail](builder::GameBuilder::food_under_tail).
+    pub fn food(&self) -> &[(usize, usize)] {
+        &self.food
+    }
+
+    /// Returns the number of [ticks](Game::ticks) since the food tile at location `(x,y)` was
+    /// placed, e.g. to let it grow on screen, or `None` if there is no food.
+    ///
+    /// Food which was there since the game was created or [restarted](Game::restart) counts from
+    /// then. Moving [food](builder::GameBuilder::mobile_food) keeps its age.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(5, 2, Tile::Food);
+    /// assert_eq!(game.food_age(5, 2), Some(0));
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert_eq!(game.food_age(5, 2), Some(2));
+    ///
+    /// game.move_snake();
+    /// assert_eq!(game.food_age(5, 2), None);
+    /// ```
+    pub fn food_age(&self, x: usize, y: usize) -> Option<u64> {
+        if !self.food.contains(&(x, y)) {
+            return None;
+        }
+
+        let spawned = self.food_spawn_ticks.get(&(x, y)).copied().unwrap_or(0);
+        Some(self.ticks.saturating_sub(spawned))
+    }
+
+    /// Enables or disables food, e.g. to let the snake move freely in a test.
+    ///
+    /// Disabling food removes every food tile and turns [Game::create_food] into a no-op, also
+    /// when [restarting](Game::restart), so the snake never eats. Enabling it again places food
+    /// up to the configured [food count](builder::GameBuilder::food_count).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.set_food_enabled(false);
+    /// assert_eq!(game.display(|map| map.count(Tile::Food)), 0);
+    ///
+    /// game.restart();
+    /// assert!(!game.create_food());
+    /// assert_eq!(game.display(|map| map.count(Tile::Food)), 0);
+    ///
+    /// game.set_food_enabled(true);
+    /// assert_eq!(game.food().len(), 1);
+    /// ```
+    pub fn set_food_enabled(&mut self, enabled: bool) {
+        self.food_enabled = enabled;
+
+        if enabled {
+            while self.food.len() < self.food_count && self.create_food() {}
+        } else {
+            self.food_spawn_ticks.clear();
+            for (x, y) in self.food.drain(..) {
+                if self.map.get(x, y) == map::Tile::Food {
+                    self.map.set(x, y, map::Tile::Empty);
+                }
+            }
+        }
+    }
+
+    /// Returns if food is [enabled](Game::set_food_enabled).
+    pub fn is_food_enabled(&self) -> bool {
+        self.food_enabled
+    }
+
+    /// Returns the fraction of the map's locations which aren't [empty](map::Tile::Empty), from
+    /// `0.0` to `1.0`, e.g. to build up tension as the board fills up.
+    ///
+    /// A map without any locations counts as full.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile};
+    /// let mut game = Game::<5, 4>::builder().food_count(0).build().unwrap();
+    /// assert_eq!(game.fill_ratio(), 1.0 / 20.0);
+    ///
+    /// game.set_tile(0, 0, Tile::Food);
+    /// for x in 0..5 {
+    ///     game.set_tile(x, 3, Tile::Wall);
+    /// }
+    /// assert_eq!(game.fill_ratio(), 7.0 / 20.0);
+    ///
+    /// assert_eq!(Game::<1, 1>::new().fill_ratio(), 1.0);
+    /// ```
+    pub fn fill_ratio(&self) -> f32 {
+        let area = W * H;
+        if area == 0 {
+            return 1.0;
+        }
+
+        (area - self.map.count(map::Tile::Empty)) as f32 / area as f32
+    }
+
+    /// Returns the map of this game, containing the [tiles](map::Tile) of the snake, food and
+    /// level.
+    ///
+    /// Unlike [display](Game::display), the reference can be kept around, as long as the game
+    /// isn't altered.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile};
+    /// let game = Game::<10, 10>::new();
+    /// let map = game.map();
+    /// assert!(map.get(5, 5) == Tile::Snake);
+    /// assert_eq!(map.count(Tile::Food), 1);
+    /// ```
+    pub fn map(&self) -> &map::Map<W, H> {
+        &self.map
+    }
+
+    /// Calls the given function with the map of this game, containing empty, snake and food
+    /// [tiles](map::Tile).
+    ///
+    /// This function is intended to only display the state of the map, therefor it receives an
+    /// immutable reference.
+    ///
+    /// Note this method is not innately concurrent, so expensive rendering should be handled
+    /// with care.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map};
+    /// let game = Game::<10, 10>::new();
+    /// game.display(|map| {
+    ///     for x in 0..10 {
+    ///         for y in 0..10 {
+    ///             match map.get(x, y) {
+    ///                 // display the tile accordingly
+    ///                 map::Tile::Empty => (),
+    ///                 // ...
+    ///                 # _ => ()
+    ///             }
+    ///         }
+    ///     }
+    /// })
+    pub fn display<F, R>(&self, func: F) -> R
+    where
+        F: FnOnce(&map::Map<W, H>) -> R,
+    {
+        func(&self.map)
+    }
+
+    /// Calls the given function with mutable access to the map of this game, e.g. to build a
+    /// level.
+    ///
+    /// Afterwards the map is validated: every location of the snake and the
+    /// [AI snakes](Game::add_ai_snake) must still hold a [snake tile](map::Tile::Snake), and there
+    /// must be no other snake tiles. If that isn't the case, the edit is undone and an [Err] is
+    /// returned. Otherwise the [food](Game::food) is updated to the food tiles on the map, with
+    /// added food counting as new.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, PartsError};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.edit_map(|map| {
+    ///     map.set(1, 1, Tile::Wall);
+    ///     map.set(8, 8, Tile::Food);
+    /// })
+    /// .unwrap();
+    /// assert!(game.map().get(1, 1) == Tile::Wall);
+    /// assert_eq!(game.food(), &[(8, 8)]);
+    /// assert_eq!(game.food_age(8, 8), Some(0));
+    ///
+    /// // Removing the snake's head is undone.
+    /// let err = game.edit_map(|map| {
+    ///     map.set(1, 1, Tile::Empty);
+    ///     map.set(5, 5, Tile::Food);
+    /// });
+    /// assert_eq!(err, Err(PartsError::MissingSnakeTile(5, 5)));
+    /// assert!(game.map().get(5, 5) == Tile::Snake);
+    /// assert!(game.map().get(1, 1) == Tile::Wall);
+    ///
+    /// // So is a snake tile without a snake.
+    /// let err = game.edit_map(|map| map.set(2, 2, Tile::Snake));
+    /// assert_eq!(err, Err(PartsError::StraySnakeTile(2, 2)));
+    /// assert!(game.map().get(2, 2) == Tile::Empty);
+    /// ```
+    pub fn edit_map<F>(&mut self, func: F) -> Result<(), PartsError>
+    where
+        F: FnOnce(&mut map::Map<W, H>),
+    {
+        let before = self.map.clone();
+        func(&mut self.map);
+
+        if let Err(err) = self.validate_snake_tiles() {
+            self.map = before;
+            return Err(err);
+        }
+
+        // Keep the food hidden under the tail, which isn't on the map yet.
+        let hidden: Vec<_> = self
+            .food
+            .iter()
+            .copied()
+            .filter(|&(x, y)| self.map.get(x, y) == map::Tile::Snake)
+            .collect();
+        let mut food: Vec<_> = self
+            .food
+            .iter()
+            .copied()
+            .filter(|&(x, y)| self.map.get(x, y) == map::Tile::Food)
+            .chain(hidden)
+            .collect();
+        for location in self.map.locations(map::Tile::Food) {
+            if !food.contains(&location) {
+                food.push(location);
+                self.food_spawn_ticks.insert(location, self.ticks);
+            }
+        }
+        self.food_spawn_ticks
+            .retain(|location, _| food.contains(location));
+        self.food = food;
+
+        Ok(())
+    }
+
+    /// Returns an [Err] if the snake tiles on the map don't match the locations of the snake and
+    /// the AI snakes.
+    fn validate_snake_tiles(&self) -> Result<(), PartsError> {
+        let snakes = || std::iter::once(&self.snake).chain(&self.ai_snakes);
+
+        if let Some((x, y)) = snakes()
+            .flat_map(|snake| snake.body())
+            .filter(|&(x, y)| self.map.in_bounds(x, y))
+            .find(|&(x, y)| self.map.get(x, y) != map::Tile::Snake)
+        {
+            return Err(PartsError::MissingSnakeTile(x, y));
+        }
+        if let Some((x, y)) = self
+            .map
+            .locations(map::Tile::Snake)
+            .find(|&(x, y)| !snakes().any(|snake| snake.occupies(x, y)))
+        {
+            return Err(PartsError::StraySnakeTile(x, y));
+        }
+
+        Ok(())
+    }
+
+    /// Pauses or resumes the game, by setting the state to [Paused](State) or [Running](State).
+    ///
+    /// This is ignored, once the game is over.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, State};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.set_paused(false);
+    /// assert!(game.state == State::Running);
+    ///
+    /// game.game_over();
+    /// game.set_paused(false);
+    /// assert!(game.state == State::GameOver);
+    /// ```
+    pub fn set_paused(&mut self, paused: bool) {
+        if self.state != State::GameOver {
+            self.state = if paused {
+                State::Paused
+            } else {
+                State::Running
+            };
+        }
+    }
+
+    /// Returns if the state is [Running](State).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::Game;
+    /// let mut game = Game::<10, 10>::new();
+    /// assert!(game.is_paused());
+    /// assert!(!game.is_running());
+    ///
+    /// game.set_paused(false);
+    /// assert!(game.is_running());
+    /// assert!(!game.is_paused());
+    ///
+    /// game.game_over();
+    /// assert!(game.is_over());
+    /// assert!(!game.is_running());
+    /// ```
+    pub fn is_running(&self) -> bool {
+        self.state == State::Running
+    }
+
+    /// Returns if the state is [Paused](State).
+    pub fn is_paused(&self) -> bool {
+        self.state == State::Paused
+    }
+
+    /// Returns if the state is [GameOver](State), so the game needs a [restart](Game::restart)
+    /// to continue.
+    pub fn is_over(&self) -> bool {
+        self.state == State::GameOver
+    }
+
+    /// Ends the game, e.g. because the player quit.
+    ///
+    /// See [Game::game_over_reason] for the reason the game ended.
+    pub fn game_over(&mut self) {
+        self.end(GameOverReason::Quit);
+    }
+
+    /// Gets called when the snake moves out of bounds or into itself, or the game ends otherwise.
+    ///
+    /// If the snake died and has [lives](Game::lives) left, it respawns instead. Otherwise this
+    /// sets the game state to [GameOver](State) and records the reason, the
+    /// [death cell](Game::death_cell) and the [event](GameEvent::GameOver).
+    fn end(&mut self, reason: GameOverReason) {
+        let died = !matches!(
+            reason,
+            GameOverReason::TimeUp
+                | GameOverReason::BoardFull
+                | GameOverReason::EmptyMap
+                | GameOverReason::Quit
+        );
+        if died {
+            if self.lives > 1 && self.respawn() {
+                debug!("life lost: {:?}", reason);
+                self.lives -= 1;
+                self.events.push(GameEvent::LifeLost(reason));
+                return;
+            }
+            self.lives = 0;
+        }
+
+        debug!("game over: {:?}", reason);
+        self.state = State::GameOver;
+        self.game_over_reason = Some(reason);
+        self.death_cell = Some(self.snake_head());
+        self.events.push(GameEvent::GameOver(reason));
+    }
+
+    /// Puts a new snake with the initial size in the middle of the map, replacing the current one.
+    ///
+    /// Returns `false` without changing anything, if the middle is blocked by something other
+    /// than the current snake or food.
+    fn respawn(&mut self) -> bool {
+        let (x, y) = (W / 2, H / 2);
+        let free = match self.map.get(x, y) {
+            map::Tile::Empty | map::Tile::Food => true,
+            map::Tile::Snake => self.snake.occupies(x, y),
+            _ => false,
+        };
+        if !free || self.ai_snakes.iter().any(|ai| ai.occupies(x, y)) {
+            return false;
+        }
+
+        self.clear_snake_tiles();
+
+        // The new head takes the place of food in the middle, which is placed elsewhere instead.
+        let replace_food = self.map.get(x, y) == map::Tile::Food;
+        if replace_food {
+            self.food.retain(|&food| food != (x, y));
+            self.food_spawn_ticks.remove(&(x, y));
+        }
+
+        self.snake = snake::Snake::new(x, y, self.initial_size);
+        self.snake.place_head(&mut self.map);
+        self.reveal_food();
+        if replace_food {
+            self.create_food();
+        }
+
+        self.heading = None;
+        self.pending_growth = 0;
+        self.hunger = 0;
+        self.invincible_ticks = self.start_grace_ticks;
+        true
+    }
+
+    /// Clears the snake's tiles from the map, except for those out of bounds after a death and
+    /// those shared with AI snakes.
+    fn clear_snake_tiles(&mut self) {
+        let body: Vec<_> = self
+            .snake
+            .body()
+            .filter(|&(x, y)| self.map.in_bounds(x, y) && self.map.get(x, y) == map::Tile::Snake)
+            .filter(|&(x, y)| !self.ai_snakes.iter().any(|ai| ai.occupies(x, y)))
+            .collect();
+        for (x, y) in body {
+            self.map.set(x, y, map::Tile::Empty);
+        }
+    }
+
+    /// Returns the number of lives the snake has left, including the current one, or `0` once it
+    /// died for the last time.
+    ///
+    /// See [GameBuilder::lives](builder::GameBuilder::lives).
+    pub fn lives(&self) -> u32 {
+        self.lives
+    }
+
+    /// Returns why the game ended or `None` if it is still going.
+    pub fn game_over_reason(&self) -> Option<GameOverReason> {
+        self.game_over_reason
+    }
+
+    /// Returns the location of the snake's head when the game ended, e.g. to draw an explosion
+    /// there, or `None` if it is still going.
+    ///
+    /// The location is out of bounds, if the snake [left the map](GameOverReason::OutOfBounds).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Left);
+    /// while game.death_cell().is_none() {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.death_cell(), Some((-1, 5)));
+    ///
+    /// // Run into the body by circling back.
+    /// let mut game = Game::<10, 10>::builder().initial_size(5).food_count(0).build().unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down, Direction::Right] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.death_cell(), Some((5, 5)));
+    ///
+    /// game.restart();
+    /// assert_eq!(game.death_cell(), None);
+    /// ```
+    pub fn death_cell(&self) -> Option<(isize, isize)> {
+        self.death_cell
+    }
+
+    /// Adds a computer controlled snake with its head at `(x,y)`, which the player has to avoid.
+    ///
+    /// AI snakes move after the player on every [move](Game::move_snake), greedily heading for
+    /// the nearest food tile while avoiding obstacles and snakes. They eat and grow, but don't
+    /// change the score. If an AI snake has nowhere to go, it stays in place. Running into an AI
+    /// snake ends the game. AI snakes are removed when the game is [restarted](Game::restart).
+    ///
+    /// Returns `false` if the location isn't [empty](map::Tile::Empty).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(5, 0, Tile::Food);
+    /// assert!(game.add_ai_snake(5, 2));
+    ///
+    /// // The AI snake heads for the food.
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// assert_eq!(game.ai_snakes()[0].y(), 1);
+    /// game.move_snake();
+    /// assert!(game.food().is_empty());
+    ///
+    /// // The player runs into its body.
+    /// game.move_snake();
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::HitAiSnake));
+    /// ```
+    pub fn add_ai_snake(&mut self, x: usize, y: usize) -> bool {
+        if self.map.get(x, y) != map::Tile::Empty {
+            return false;
+        }
+
+        let ai = snake::Snake::new(x, y, INITIAL_SNAKE_SIZE);
+        ai.place_head(&mut self.map);
+        self.ai_snakes.push(ai);
+        true
+    }
+
+    /// The [AI snakes](Game::add_ai_snake) on the map.
+    pub fn ai_snakes(&self) -> &[snake::Snake] {
+        &self.ai_snakes
+    }
+
+    /// Moves every AI snake one step towards the nearest food tile, see [Game::add_ai_snake].
+    fn move_ai_snakes(&mut self) {
+        for i in 0..self.ai_snakes.len() {
+            let ai = &self.ai_snakes[i];
+            let current = ai.direction();
+            let (x, y) = (ai.x(), ai.y());
+
+            let next = snake::Direction::ALL
+                .into_iter()
+                .filter(|dir| !current.opposite(*dir))
+                .map(|dir| (dir, self.step(x, y, dir)))
+                .filter(|&(_, (x, y))| {
+                    matches!(self.tile_at(x, y), Some(map::Tile::Empty | map::Tile::Food))
+                })
+                .min_by_key(|&(_, (x, y))| {
+                    self.food
+                        .iter()
+                        .map(|&food| self.distance_between((x, y), food))
+                        .min()
+                });
+
+            let Some((dir, (x, y))) = next else {
+                continue;
+            };
+            let ate = self.map.get(x as usize, y as usize) == map::Tile::Food;
+
+            let ai = &mut self.ai_snakes[i];
+            ai.turn(dir);
+            if ate {
+                ai.size += self.growth;
+            }
+            ai.forward_to(x, y);
+            ai.cut_tail(&mut self.map);
+            ai.place_head(&mut self.map);
+
+            if ate {
+                self.food.retain(|&food| food != (x as usize, y as usize));
+                self.food_spawn_ticks.remove(&(x as usize, y as usize));
+                if self.food.len() < self.food_count {
+                    self.create_food();
+                }
+            }
+        }
+    }
+
+    /// Increases the snake's size by the given amount, up to the
+    /// [maximum size](builder::GameBuilder::max_size).
+    fn grow(&mut self, amount: usize) {
+        let size = self.snake.size + amount;
+        self.snake.size = match self.max_size {
+            Some(max) => size.min(max.max(self.snake.size)),
+            None => size,
+        };
+    }
+
+    /// Returns if the snake reached the [maximum size](builder::GameBuilder::max_size), so it
+    /// doesn't grow anymore.
+    pub fn is_max_size(&self) -> bool {
+        self.max_size.is_some_and(|max| self.snake.size >= max)
+    }
+
+    /// Queues the given number of segments for the snake to grow by, one per
+    /// [move](Game::move_snake), without eating food.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.move_snake();
+    /// assert_eq!(game.snake_body().count(), 3);
+    ///
+    /// game.queue_growth(5);
+    /// game.turn_snake(Direction::Left);
+    /// for _ in 0..5 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_body().count(), 8);
+    /// assert_eq!(game.pending_growth(), 0);
+    /// ```
+    pub fn queue_growth(&mut self, segments: usize) {
+        self.pending_growth += segments;
+    }
+
+    /// Returns the number of [queued](Game::queue_growth) segments the snake hasn't grown by yet.
+    pub fn pending_growth(&self) -> usize {
+        self.pending_growth
+    }
+
+    /// Decreases the size of the snake by the given amount, removing its oldest tail pieces.
+    ///
+    /// If the size drops to zero, the game is over.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, State};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.shrink_snake(2);
+    /// assert!(game.state != State::GameOver);
+    ///
+    /// game.shrink_snake(1);
+    /// assert!(game.state == State::GameOver);
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::Shrunk));
+    /// ```
+    pub fn shrink_snake(&mut self, amount: usize) {
+        self.snake.shrink(amount, &mut self.map);
+        self.reveal_food();
+
+        if self.snake.size == 0 {
+            self.end(GameOverReason::Shrunk);
+        }
+    }
+
+    /// Returns every [GameEvent] since the game was created or [restarted](Game::restart), from
+    /// oldest to newest.
+    ///
+    /// The log is kept for post-processing after the game ended. Since only actual changes of
+    /// direction, eaten food and the game over are recorded, it grows at most by a few events per
+    /// move, but it isn't bounded otherwise. Long running games should
+    /// [clear](Game::clear_event_log) it regularly.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameEvent, GameOverReason, snake::Direction};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.turn_snake(Direction::Up);
+    /// game.turn_snake(Direction::Up);
+    /// while game.game_over_reason().is_none() {
+    ///     game.move_snake();
+    /// }
+    ///
+    /// assert_eq!(game.event_log()[0], GameEvent::Turned(Direction::Up));
+    /// assert_eq!(
+    ///     game.event_log().last(),
+    ///     Some(&GameEvent::GameOver(GameOverReason::OutOfBounds))
+    /// );
+    ///
+    /// game.restart();
+    /// assert!(game.event_log().is_empty());
+    /// ```
+    pub fn event_log(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Removes every event from the [event log](Game::event_log).
+    pub fn clear_event_log(&mut self) {
+        self.events.clear();
+    }
+
+    /// Clears the map, initializes a new snake and sets the state to [Paused](State).
+    ///
+    /// The configured number of food tiles will be [generated](Game::create_food) and the snake
+    /// is [invincible](Game::is_invincible) for the configured
+    /// [grace period](builder::GameBuilder::start_grace_ticks).
+    pub fn restart(&mut self) {
+        self.restart_preserving(false, false);
+    }
+
+    /// [Restarts](Game::restart) the game, optionally keeping the food tiles and the
+    /// [walls](map::Tile::Wall) and [one-way](map::Tile::OneWay) tiles where they are.
+    ///
+    /// When keeping the food, the random number generator is also reset to its state after the
+    /// last start with new food, so retrying the same setup with the same inputs plays out
+    /// identically. Food on the center of the map, where the snake starts, is moved to a random
+    /// location instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.add_wall_ring(1).unwrap();
+    /// game.set_tile(2, 2, Tile::Food);
+    /// game.set_tile(7, 7, Tile::Food);
+    /// let start = game.map().snapshot();
+    ///
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..3 {
+    ///     game.move_snake();
+    /// }
+    /// assert_ne!(game.map().snapshot(), start);
+    ///
+    /// game.restart_preserving(true, true);
+    /// assert_eq!(game.map().snapshot(), start);
+    ///
+    /// // The snake starts on top of the food.
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..3 {
+    ///     game.move_snake();
+    /// }
+    /// assert!(game.set_tile(5, 5, Tile::Food));
+    ///
+    /// game.restart_preserving(true, false);
+    /// assert_eq!(game.food().len(), 1);
+    /// assert_ne!(game.food(), &[(5, 5)]);
+    /// ```
+    pub fn restart_preserving(&mut self, keep_food: bool, keep_walls: bool) {
+        let food = if keep_food {
+            std::mem::take(&mut self.food)
+        } else {
+            Vec::new()
+        };
+        let walls = if keep_walls {
+            (0..W)
+                .flat_map(|x| (0..H).map(move |y| (x, y)))
+                .map(|(x, y)| (x, y, self.map.get(x, y)))
+                .filter(|(_, _, tile)| matches!(tile, map::Tile::Wall | map::Tile::OneWay(_)))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        self.map.clear();
+        self.food.clear();
+        for (x, y, tile) in walls {
+            self.map.set(x, y, tile);
+        }
+
+        let empty = W == 0 || H == 0;
+        self.snake = snake::Snake::new(W / 2, H / 2, self.initial_size);
+        if !empty {
+            self.snake.place_head(&mut self.map);
+        }
+        if keep_food {
+            self.rng = self.start_rng.clone();
+            for (x, y) in food {
+                if !self.set_tile(x, y, map::Tile::Food) {
+                    self.create_food();
+                }
+            }
+        } else {
+            for _ in 0..self.food_count {
+                self.create_food();
+            }
+            self.start_rng = self.rng.clone();
+        }
+
+        self.state = State::Paused;
+        self.invincible_ticks = self.start_grace_ticks;
+        self.events.clear();
+        self.game_over_reason = None;
+        self.ticks = 0;
+        self.score = 0;
+        self.hunger = 0;
+        self.last_outcome = MoveOutcome::Idle;
+        self.boost_ticks = 0;
+        self.pending_growth = 0;
+        self.ai_snakes.clear();
+        self.heading = None;
+        self.foods_eaten = 0;
+        self.boosts = 0;
+        self.last_food_tick = 0;
+        self.arena_inset = 0;
+        self.death_cell = None;
+        self.lives = self.max_lives;
+        self.food_spawn_ticks = self.food.iter().map(|&food| (food, 0)).collect();
+
+        if empty {
+            self.end(GameOverReason::EmptyMap);
+        }
+    }
+
+    /// Reverses the snake, so its oldest tail piece becomes the head, e.g. as a power-up.
+    ///
+    /// The snake faces away from the rest of its body, continuing the path of the tail backwards,
+    /// like [snake::Snake::reverse]. If that move isn't [safe](Game::is_safe), the snake faces
+    /// the first safe direction of left, right, up and down instead, which doesn't lead onto its
+    /// new neck.
+    ///
+    /// Returns an [Err] without changing the snake if no move would be safe after reversing.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().initial_size(4).food_count(0).build().unwrap();
+    /// for dir in [Direction::Up, Direction::Up, Direction::Right] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// // The L-shaped snake continues its tail's path downwards.
+    /// game.reverse_snake().unwrap();
+    /// assert_eq!(game.snake_head(), (5, 5));
+    /// assert_eq!(game.snake_direction(), Direction::Down);
+    /// let body: Vec<_> = game.snake_body().collect();
+    /// assert_eq!(body, vec![(6, 3), (5, 3), (5, 4), (5, 5)]);
+    ///
+    /// // Back at the other end, a wall blocks the way, so the snake turns left.
+    /// game.set_tile(5, 3, Tile::Empty);
+    /// game.set_tile(7, 3, Tile::Wall);
+    /// game.reverse_snake().unwrap();
+    /// assert_eq!(game.snake_head(), (6, 3));
+    /// assert_eq!(game.snake_direction(), Direction::Up);
+    /// ```
+    ///
+    /// Unlike [turning](Game::turn_snake), reversing sets the opposite direction:
+    /// ```
+    /// use rust_snake::{Game, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Up);
+    /// game.move_snake();
+    /// game.move_snake();
+    ///
+    /// game.reverse_snake().unwrap();
+    /// assert_eq!(game.snake_direction(), Direction::Down);
+    ///
+    /// // The player still can't turn around.
+    /// game.turn_snake(Direction::Up);
+    /// assert_eq!(game.snake_direction(), Direction::Down);
+    /// ```
+    pub fn reverse_snake(&mut self) -> Result<(), ReverseError> {
+        // The head outside the map can't become part of the tail.
+        if !self.snake.in_bounds(&self.map) {
+            return Err(ReverseError);
+        }
+
+        let (direction, heading) = (self.snake.direction(), self.heading);
+        self.heading = None;
+        self.snake.reverse();
+
+        let neck = self.snake.neck();
+        let dir = std::iter::once(self.snake.direction())
+            .chain(snake::Direction::ALL)
+            .filter(|&dir| dir != snake::Direction::None && self.is_safe(dir))
+            .find(|&dir| {
+                let (x, y) = self.destination(dir);
+                neck != Some((x as usize, y as usize))
+            });
+
+        match dir {
+            Some(dir) => {
+                self.snake.set_direction(dir);
+                self.events.push(GameEvent::Turned(dir));
+                Ok(())
+            }
+            None => {
+                // Reversing twice restores the body.
+                self.snake.reverse();
+                self.snake.set_direction(direction);
+                self.heading = heading;
+                Err(ReverseError)
+            }
+        }
+    }
+
+    /// Moves the snake's head to the center of the map, where a new game starts.
+    ///
+    /// The tail is truncated rather than translated: every snake tile is cleared and only the
+    /// head is placed at the center. The snake keeps its size and direction, so the tail grows
+    /// back over the next moves. Food hidden under the tail is revealed.
+    ///
+    /// Returns an [Err] without changing the game if the center is neither empty nor occupied by
+    /// the snake, e.g. because of a [wall](map::Tile::Wall) or food, or if the map is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{map::Tile, snake::Direction, Game, RecenterError};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Right);
+    /// for _ in 0..3 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.snake_head(), (8, 5));
+    ///
+    /// game.recenter().unwrap();
+    /// assert_eq!(game.snake_head(), (5, 5));
+    /// assert_eq!(game.snake_direction(), Direction::Right);
+    /// game.display(|map| {
+    ///     assert_eq!(map.count(Tile::Snake), 1);
+    ///     assert!(map.get(5, 5) == Tile::Snake);
+    /// });
+    ///
+    /// for _ in 0..3 {
+    ///     game.move_snake();
+    /// }
+    /// game.set_tile(5, 5, Tile::Wall);
+    /// assert_eq!(game.recenter(), Err(RecenterError(5, 5)));
+    /// assert_eq!(game.snake_head(), (8, 5));
+    ///
+    /// assert_eq!(Game::<0, 4>::new().recenter(), Err(RecenterError(0, 2)));
+    /// ```
+    ///
+    /// After a death, the parts of the snake outside the map or on an AI snake are left alone:
+    /// ```
+    /// use rust_snake::{map::Tile, snake::Direction, Game, GameOverReason};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.turn_snake(Direction::Right);
+    /// while !game.is_over() {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::OutOfBounds));
+    /// game.recenter().unwrap();
+    /// assert_eq!(game.snake_head(), (5, 5));
+    ///
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// game.set_tile(5, 0, Tile::Food);
+    /// game.add_ai_snake(5, 2);
+    /// game.turn_snake(Direction::Up);
+    /// for _ in 0..3 {
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(game.game_over_reason(), Some(GameOverReason::HitAiSnake));
+    ///
+    /// game.recenter().unwrap();
+    /// game.display(|map| {
+    ///     for (x, y) in game.ai_snakes()[0].body() {
+    ///         assert!(map.get(x, y) == Tile::Snake);
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Food hidden under the tail shows up again:
+    /// ```
+    /// use rust_snake::{map::Tile, snake::Direction, Game};
+    /// let mut game = Game::<4, 2>::builder()
+    ///     .initial_size(4)
+    ///     .food_count(0)
+    ///     .food_under_tail()
+    ///     .build()
+    ///     .unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down, Direction::Right] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    /// for (x, y) in [(0, 0), (0, 1), (3, 0), (3, 1)] {
+    ///     game.set_tile(x, y, Tile::Food);
+    /// }
+    /// // The only room left is under the tail.
+    /// assert!(game.create_food());
+    /// assert!(game.food().contains(&(2, 0)));
+    ///
+    /// game.recenter().unwrap();
+    /// assert!(game.food().contains(&(2, 0)));
+    /// game.display(|map| assert!(map.get(2, 0) == Tile::Food));
+    /// ```
+    pub fn recenter(&mut self) -> Result<(), RecenterError> {
+        let (cx, cy) = (W / 2, H / 2);
+        // An empty map has no center.
+        if !self.map.in_bounds(cx, cy)
+            || (self.map.get(cx, cy) != map::Tile::Empty && !self.snake.occupies(cx, cy))
+            || self.ai_snakes.iter().any(|ai| ai.occupies(cx, cy))
+        {
+            return Err(RecenterError(cx, cy));
+        }
+
+        self.clear_snake_tiles();
+
+        let mut snake = snake::Snake::new(cx, cy, self.snake.size);
+        snake.set_direction(self.snake.direction());
+        self.snake = snake;
+        self.snake.place_head(&mut self.map);
+        self.reveal_food();
+        Ok(())
     }
 }
 
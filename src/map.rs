@@ -1,4 +1,9 @@
+use crate::snake::Direction;
+use std::collections::HashSet;
+use std::fmt;
+
 /// Struct representing the map, containing snake and food locations.
+#[derive(PartialEq, Clone)]
 pub struct Map<const W: usize, const H: usize> {
     data: [[Tile; H]; W],
 }
@@ -12,19 +17,446 @@ impl<const W: usize, const H: usize> Map<W, H> {
     }
 
     /// Returns the [Tile] at location `(x,y)`.
+    ///
+    /// # Panics
+    ///
+    /// If the location is out of bounds, see [Map::try_get].
     pub fn get(&self, x: usize, y: usize) -> Tile {
         assert!(self.in_bounds(x, y));
 
         self.data[x][y]
     }
 
+    /// Returns the [Tile] at location `(x,y)` or an [Err] if the location is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, MapError};
+    /// let map = Map::<3, 2>::new();
+    /// assert!(map.try_get(2, 1).is_ok());
+    /// assert_eq!(map.try_get(1, 2).err(), Some(MapError::OutOfBounds(1, 2)));
+    /// ```
+    pub fn try_get(&self, x: usize, y: usize) -> Result<Tile, MapError> {
+        if !self.in_bounds(x, y) {
+            return Err(MapError::OutOfBounds(x, y));
+        }
+
+        Ok(self.data[x][y])
+    }
+
     /// Sets the [Tile] at location `(x,y)`.
+    ///
+    /// # Panics
+    ///
+    /// If the location is out of bounds, see [Map::try_set].
     pub fn set(&mut self, x: usize, y: usize, tile: Tile) {
         assert!(self.in_bounds(x, y));
 
         self.data[x][y] = tile;
     }
 
+    /// Sets the [Tile] at location `(x,y)` or returns an [Err] if the location is out of bounds.
+    pub fn try_set(&mut self, x: usize, y: usize, tile: Tile) -> Result<(), MapError> {
+        if !self.in_bounds(x, y) {
+            return Err(MapError::OutOfBounds(x, y));
+        }
+
+        self.data[x][y] = tile;
+        Ok(())
+    }
+
+    /// Sets every location to the given [Tile].
+    pub fn fill(&mut self, tile: Tile) {
+        self.data = [[tile; H]; W];
+    }
+
+    /// Sets every location to [Tile::Empty].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<4, 3>::new();
+    /// map.fill(Tile::Food);
+    /// map.set(1, 2, Tile::Snake);
+    ///
+    /// map.clear();
+    /// assert_eq!(map.count(Tile::Empty), 4 * 3);
+    /// ```
+    pub fn clear(&mut self) {
+        self.fill(Tile::Empty);
+    }
+
+    /// Returns the number of locations containing the given [Tile].
+    pub fn count(&self, tile: Tile) -> usize {
+        self.data.iter().flatten().filter(|t| **t == tile).count()
+    }
+
+    /// Iterates over the locations containing the given [Tile], column by column.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<4, 3>::new();
+    /// map.set(2, 1, Tile::Food);
+    /// map.set(0, 2, Tile::Food);
+    ///
+    /// assert_eq!(map.locations(Tile::Food).collect::<Vec<_>>(), vec![(0, 2), (2, 1)]);
+    /// ```
+    pub fn locations(&self, tile: Tile) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..W)
+            .flat_map(|x| (0..H).map(move |y| (x, y)))
+            .filter(move |&(x, y)| self.data[x][y] == tile)
+    }
+
+    /// Iterates over the locations and tiles of the window `w` tiles wide and `h` tiles high, with
+    /// its top left corner at `(x0,y0)`, row by row.
+    ///
+    /// The window is clamped to the bounds of the map, so parts of it outside of the map are
+    /// skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<5, 4>::new();
+    /// map.set(2, 1, Tile::Food);
+    ///
+    /// let cells: Vec<_> = map.region(1, 1, 2, 2).map(|(x, y, _)| (x, y)).collect();
+    /// assert_eq!(cells, vec![(1, 1), (2, 1), (1, 2), (2, 2)]);
+    /// assert_eq!(map.region(1, 1, 2, 2).filter(|&(_, _, tile)| tile == Tile::Food).count(), 1);
+    ///
+    /// // Clamped at the bottom right corner.
+    /// let cells: Vec<_> = map.region(3, 2, 10, 10).map(|(x, y, _)| (x, y)).collect();
+    /// assert_eq!(cells, vec![(3, 2), (4, 2), (3, 3), (4, 3)]);
+    /// assert_eq!(map.region(5, 0, 2, 2).count(), 0);
+    /// ```
+    pub fn region(
+        &self,
+        x0: usize,
+        y0: usize,
+        w: usize,
+        h: usize,
+    ) -> impl Iterator<Item = (usize, usize, Tile)> + '_ {
+        let (x1, y1) = (x0.saturating_add(w).min(W), y0.saturating_add(h).min(H));
+
+        (y0..y1)
+            .flat_map(move |y| (x0..x1).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, self.data[x][y]))
+    }
+
+    /// Returns a copy of this map rotated by 90 degrees clockwise.
+    ///
+    /// Note the width and height of the returned map are swapped. The directions of
+    /// [one-way](Tile::OneWay) tiles are rotated as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<3, 2>::new();
+    /// map.set(0, 0, Tile::Food);
+    /// map.set(2, 1, Tile::Snake);
+    ///
+    /// let rotated: Map<2, 3> = map.rotate_cw();
+    /// assert!(rotated.get(1, 0) == Tile::Food);
+    /// assert!(rotated.get(0, 2) == Tile::Snake);
+    /// assert!(rotated.rotate_ccw().get(0, 0) == Tile::Food);
+    /// ```
+    pub fn rotate_cw(&self) -> Map<H, W> {
+        let mut map = Map::<H, W>::new();
+        for x in 0..W {
+            for y in 0..H {
+                map.set(H - 1 - y, x, self.get(x, y).transform(Direction::rotate_cw));
+            }
+        }
+
+        map
+    }
+
+    /// Returns a copy of this map rotated by 90 degrees counterclockwise.
+    ///
+    /// Note the width and height of the returned map are swapped. The directions of
+    /// [one-way](Tile::OneWay) tiles are rotated as well.
+    pub fn rotate_ccw(&self) -> Map<H, W> {
+        let mut map = Map::<H, W>::new();
+        for x in 0..W {
+            for y in 0..H {
+                map.set(
+                    y,
+                    W - 1 - x,
+                    self.get(x, y).transform(Direction::rotate_ccw),
+                );
+            }
+        }
+
+        map
+    }
+
+    /// Returns a copy of this map mirrored along the vertical axis, swapping left and right.
+    ///
+    /// The directions of [one-way](Tile::OneWay) tiles are mirrored as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// use rust_snake::snake::Direction;
+    /// let mut map = Map::<3, 2>::new();
+    /// map.set(0, 1, Tile::OneWay(Direction::Right));
+    ///
+    /// assert!(map.flip_horizontal().get(2, 1) == Tile::OneWay(Direction::Left));
+    /// assert!(map.flip_vertical().get(0, 0) == Tile::OneWay(Direction::Right));
+    /// ```
+    pub fn flip_horizontal(&self) -> Map<W, H> {
+        let mut map = Map::new();
+        for x in 0..W {
+            for y in 0..H {
+                let tile = self.get(x, y).transform(|dir| match dir {
+                    Direction::Left | Direction::Right => dir.inverse(),
+                    _ => *dir,
+                });
+                map.set(W - 1 - x, y, tile);
+            }
+        }
+
+        map
+    }
+
+    /// Returns a copy of this map mirrored along the horizontal axis, swapping top and bottom.
+    ///
+    /// The directions of [one-way](Tile::OneWay) tiles are mirrored as well.
+    pub fn flip_vertical(&self) -> Map<W, H> {
+        let mut map = Map::new();
+        for x in 0..W {
+            for y in 0..H {
+                let tile = self.get(x, y).transform(|dir| match dir {
+                    Direction::Up | Direction::Down => dir.inverse(),
+                    _ => *dir,
+                });
+                map.set(x, H - 1 - y, tile);
+            }
+        }
+
+        map
+    }
+
+    /// Returns a hash of all tiles, which is stable across runs and platforms.
+    ///
+    /// Maps with the same tiles have the same hash, so comparing hashes is a compact way to check
+    /// a sequence of maps, e.g. in golden tests.
+    pub fn hash_tiles(&self) -> u64 {
+        // 64 bit FNV-1a over the tile codes, column by column.
+        self.data
+            .iter()
+            .flatten()
+            .fold(0xcbf29ce484222325, |hash, tile| {
+                (hash ^ tile.code() as u64).wrapping_mul(0x100000001b3)
+            })
+    }
+
+    /// Captures the current tiles as a [MapSnapshot].
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, MapSnapshot, Tile};
+    /// let mut map = Map::<2, 2>::new();
+    /// let empty = map.snapshot();
+    /// assert_eq!(empty, MapSnapshot(0x4d25767f9dce13f5));
+    ///
+    /// map.set(1, 0, Tile::Food);
+    /// assert_ne!(map.snapshot(), empty);
+    ///
+    /// map.clear();
+    /// assert_eq!(map.snapshot(), empty);
+    /// ```
+    pub fn snapshot(&self) -> MapSnapshot {
+        MapSnapshot(self.hash_tiles())
+    }
+
+    /// Encodes the map as one byte per tile, row by row from the top left, e.g. to pass it to
+    /// other languages or to log it.
+    ///
+    /// The byte at index `y * W + x` holds the tile at location `(x,y)`, encoded as follows:
+    ///
+    /// | Tile | Byte |
+    /// |------|------|
+    /// | [Tile::Empty] | `0` |
+    /// | [Tile::Snake] | `1` |
+    /// | [Tile::Food] | `2` |
+    /// | [Tile::OneWay] facing left, right, up, down or none | `3` to `7` |
+    /// | [Tile::Wall] | `8` |
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// use rust_snake::snake::Direction;
+    /// let mut map = Map::<3, 2>::new();
+    /// map.set(1, 0, Tile::Food);
+    /// map.set(0, 1, Tile::Snake);
+    /// map.set(2, 1, Tile::OneWay(Direction::Up));
+    ///
+    /// let flat = map.to_flat();
+    /// assert_eq!(flat.len(), 3 * 2);
+    /// assert_eq!(flat, vec![0, 2, 0, 1, 0, 5]);
+    /// ```
+    pub fn to_flat(&self) -> Vec<u8> {
+        (0..H)
+            .flat_map(|y| (0..W).map(move |x| (x, y)))
+            .map(|(x, y)| self.data[x][y].code())
+            .collect()
+    }
+
+    /// Decodes a map [encoded](Map::to_flat) as one byte per tile.
+    ///
+    /// Returns `None` if the number of bytes doesn't match the size of the map or a byte doesn't
+    /// encode a tile.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<3, 2>::new();
+    /// map.set(1, 0, Tile::Food);
+    /// map.set(2, 1, Tile::Wall);
+    ///
+    /// assert!(Map::<3, 2>::from_flat(&map.to_flat()) == Some(map));
+    /// assert!(Map::<3, 2>::from_flat(&[0, 1, 2]).is_none());
+    /// assert!(Map::<3, 2>::from_flat(&[0, 0, 0, 0, 0, 9]).is_none());
+    /// ```
+    pub fn from_flat(flat: &[u8]) -> Option<Self> {
+        if flat.len() != W * H {
+            return None;
+        }
+
+        let mut map = Map::new();
+        for (i, &code) in flat.iter().enumerate() {
+            map.data[i % W][i / W] = Tile::from_code(code)?;
+        }
+
+        Some(map)
+    }
+
+    /// Renders the map as a binary PPM image, drawing every tile as a square of `cell_px` pixels.
+    ///
+    /// The tiles are colored like the default terminal style. PPM images need no dependencies and
+    /// can be converted to other formats by most image tools.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<3, 2>::new();
+    /// map.set(1, 0, Tile::Food);
+    ///
+    /// let image = map.to_ppm(4);
+    /// let header = b"P6\n12 8\n255\n";
+    /// assert!(image.starts_with(header));
+    /// assert_eq!(image.len(), header.len() + 12 * 8 * 3);
+    ///
+    /// // The fifth pixel of the first row belongs to the food tile.
+    /// let pixel = header.len() + 4 * 3;
+    /// assert_ne!(image[pixel..pixel + 3], [0, 0, 0]);
+    /// ```
+    pub fn to_ppm(&self, cell_px: usize) -> Vec<u8> {
+        let (width, height) = (W * cell_px, H * cell_px);
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        image.reserve(width * height * 3);
+
+        for py in 0..height {
+            for px in 0..width {
+                image.extend_from_slice(&self.get(px / cell_px, py / cell_px).default_color());
+            }
+        }
+
+        image
+    }
+
+    /// Iterates over the orthogonally adjacent locations of `(x,y)`, which are in bounds.
+    ///
+    /// The map doesn't know about wrapping, see [Game::neighbors](crate::Game::neighbors) for
+    /// that.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::Map;
+    /// let map = Map::<3, 3>::new();
+    /// assert_eq!(map.neighbors(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    /// assert_eq!(map.neighbors(1, 1).count(), 4);
+    /// ```
+    pub fn neighbors(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        Direction::ALL.into_iter().filter_map(move |dir| {
+            let nx = x.checked_add_signed(dir.x())?;
+            let ny = y.checked_add_signed(dir.y())?;
+            (nx < W && ny < H).then_some((nx, ny))
+        })
+    }
+
+    /// Returns every location reachable from `start` through [neighbors](Map::neighbors), without
+    /// entering a tile for which `blocked` returns `true`.
+    ///
+    /// The result includes `start`, unless it is blocked itself, in which case it is empty.
+    ///
+    /// # Panics
+    ///
+    /// If `start` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::{Map, Tile};
+    /// let mut map = Map::<5, 4>::new();
+    /// for y in 0..4 {
+    ///     map.set(2, y, Tile::Wall);
+    /// }
+    ///
+    /// let left = map.flood_fill((0, 0), |tile| tile == Tile::Wall);
+    /// assert_eq!(left.len(), 2 * 4);
+    /// assert!(left.contains(&(1, 3)) && !left.contains(&(3, 0)));
+    ///
+    /// let right = map.flood_fill((4, 3), |tile| tile == Tile::Wall);
+    /// assert_eq!(right.len(), 2 * 4);
+    /// assert!(left.is_disjoint(&right));
+    ///
+    /// assert!(map.flood_fill((2, 0), |tile| tile == Tile::Wall).is_empty());
+    /// ```
+    pub fn flood_fill(
+        &self,
+        start: (usize, usize),
+        blocked: impl Fn(Tile) -> bool,
+    ) -> HashSet<(usize, usize)> {
+        let mut reached = HashSet::new();
+        if blocked(self.get(start.0, start.1)) {
+            return reached;
+        }
+
+        reached.insert(start);
+        let mut stack = vec![start];
+        while let Some((x, y)) = stack.pop() {
+            for (nx, ny) in self.neighbors(x, y) {
+                if !blocked(self.get(nx, ny)) && reached.insert((nx, ny)) {
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        reached
+    }
+
+    /// Wraps the location `(x,y)` around the boundaries of the map, so it is always in bounds.
+    ///
+    /// # Panics
+    ///
+    /// If the map is empty, since there is no location to wrap to.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::Map;
+    /// let map = Map::<4, 3>::new();
+    /// assert_eq!(map.wrap_coords(1, 2), (1, 2));
+    /// assert_eq!(map.wrap_coords(-1, -4), (3, 2));
+    /// assert_eq!(map.wrap_coords(9, 3), (1, 0));
+    /// ```
+    pub fn wrap_coords(&self, x: isize, y: isize) -> (usize, usize) {
+        (
+            x.rem_euclid(W as isize) as usize,
+            y.rem_euclid(H as isize) as usize,
+        )
+    }
+
     /// Returns if the requested location `(x,y)` is contained.
     pub fn in_bounds(&self, x: usize, y: usize) -> bool {
         x < W && y < H
@@ -37,10 +469,155 @@ impl<const W: usize, const H: usize> Default for Map<W, H> {
     }
 }
 
+/// A compact capture of the tiles of a [Map], see [Map::snapshot].
+///
+/// Snapshots of maps with the same tiles are equal, while snapshots of different maps are
+/// practically always different.
+///
+/// # Examples
+/// ```
+/// use rust_snake::{Game, snake::Direction};
+///
+/// // Seeded games with the same inputs go through the same maps.
+/// let run = || {
+///     let mut game = Game::<10, 10>::builder().seed(7).build().unwrap();
+///     game.turn_snake(Direction::Left);
+///     (0..8)
+///         .map(|_| {
+///             game.move_snake();
+///             game.map().snapshot()
+///         })
+///         .collect::<Vec<_>>()
+/// };
+/// assert_eq!(run(), run());
+/// ```
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct MapSnapshot(pub u64);
+
+/// The errors of fallible [Map] operations.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum MapError {
+    /// The location `(x,y)` isn't on the map.
+    OutOfBounds(usize, usize),
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapError::OutOfBounds(x, y) => write!(f, "location ({x}, {y}) is out of bounds"),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
 /// The Tiles contained in the [Map].
 #[derive(PartialEq, Copy, Clone)]
 pub enum Tile {
     Empty,
     Snake,
     Food,
+    /// A tile the snake can only pass through in the given direction, acting as a wall otherwise.
+    OneWay(Direction),
+    /// A tile the snake can't pass through.
+    Wall,
+}
+
+impl Tile {
+    /// Returns a number identifying this tile, including its direction.
+    ///
+    /// The numbers are part of the encoding of [Map::to_flat], so they must not change.
+    fn code(&self) -> u8 {
+        match self {
+            Tile::Empty => 0,
+            Tile::Snake => 1,
+            Tile::Food => 2,
+            Tile::OneWay(Direction::Left) => 3,
+            Tile::OneWay(Direction::Right) => 4,
+            Tile::OneWay(Direction::Up) => 5,
+            Tile::OneWay(Direction::Down) => 6,
+            Tile::OneWay(Direction::None) => 7,
+            Tile::Wall => 8,
+        }
+    }
+
+    /// Returns the tile identified by the given [code](Tile::code), if there is one.
+    fn from_code(code: u8) -> Option<Tile> {
+        Tile::all().iter().copied().find(|tile| tile.code() == code)
+    }
+
+    /// Every tile, including one [one-way](Tile::OneWay) tile for each direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::Tile;
+    /// use rust_snake::snake::Direction::{self, *};
+    /// let all = Tile::all();
+    /// assert_eq!(all.len(), 9);
+    ///
+    /// for tile in [Tile::Empty, Tile::Snake, Tile::Food, Tile::Wall] {
+    ///     assert!(all.contains(&tile));
+    /// }
+    /// for dir in [Left, Right, Up, Down, Direction::None] {
+    ///     assert!(all.contains(&Tile::OneWay(dir)));
+    /// }
+    /// ```
+    pub fn all() -> &'static [Tile] {
+        &[
+            Tile::Empty,
+            Tile::Snake,
+            Tile::Food,
+            Tile::OneWay(Direction::Left),
+            Tile::OneWay(Direction::Right),
+            Tile::OneWay(Direction::Up),
+            Tile::OneWay(Direction::Down),
+            Tile::OneWay(Direction::None),
+            Tile::Wall,
+        ]
+    }
+
+    /// The character representing this tile in plain text.
+    ///
+    /// One-way tiles point in their direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::Tile;
+    /// use rust_snake::snake::Direction;
+    /// assert_eq!(Tile::Food.default_symbol(), '*');
+    /// assert_eq!(Tile::OneWay(Direction::Up).default_symbol(), '^');
+    /// ```
+    pub fn default_symbol(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Snake => 'o',
+            Tile::Food => '*',
+            Tile::OneWay(Direction::Left) => '<',
+            Tile::OneWay(Direction::Right) => '>',
+            Tile::OneWay(Direction::Up) => '^',
+            Tile::OneWay(Direction::Down) => 'v',
+            Tile::OneWay(Direction::None) => '+',
+            Tile::Wall => '#',
+        }
+    }
+
+    /// The color of this tile as RGB values, as used in [rendered images](Map::to_ppm) and the
+    /// default terminal style.
+    pub fn default_color(&self) -> [u8; 3] {
+        match self {
+            Tile::Empty => [0, 0, 0],
+            Tile::Snake => [0, 170, 0],
+            Tile::Food => [230, 200, 0],
+            Tile::OneWay(_) => [0, 0, 200],
+            Tile::Wall => [128, 128, 128],
+        }
+    }
+
+    /// Returns this tile with its direction transformed by the given function, if it has one.
+    fn transform(self, func: impl Fn(&Direction) -> Direction) -> Tile {
+        match self {
+            Tile::OneWay(dir) => Tile::OneWay(func(&dir)),
+            tile => tile,
+        }
+    }
 }
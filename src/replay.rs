@@ -0,0 +1,200 @@
+//! Recording the inputs of a game to play it back later, e.g. as a ghost to race against.
+
+use crate::{map, snake, Game};
+
+/// The directions the snake moved in during a game, one for every [tick](Game::ticks), including
+/// [diagonal](crate::builder::GameBuilder::diagonal) ones.
+///
+/// Since only the inputs are recorded, playing a replay back requires a game with the same
+/// configuration and [seed](crate::builder::GameBuilder::seed) as the recorded one.
+///
+/// # Examples
+/// ```
+/// use rust_snake::{Game, replay::Replay, snake::Direction};
+/// let new_game = || Game::<10, 10>::builder().seed(7).build().unwrap();
+///
+/// let mut game = new_game();
+/// let mut replay = Replay::new();
+/// let mut heads = Vec::new();
+/// for dir in [Direction::Up, Direction::Up, Direction::Left, Direction::Down] {
+///     game.turn_snake(dir);
+///     replay.record(game.snake_heading());
+///     game.move_snake();
+///     heads.push(game.snake_head());
+/// }
+///
+/// // The ghost follows the recorded run tick by tick.
+/// let mut ghost = replay.cursor(new_game());
+/// for head in heads {
+///     assert!(ghost.advance());
+///     assert_eq!(ghost.head(), head);
+/// }
+/// assert!(!ghost.advance());
+/// ```
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Replay {
+    inputs: Vec<snake::Direction8>,
+}
+
+impl Replay {
+    /// Creates an empty replay.
+    pub fn new() -> Self {
+        Replay { inputs: Vec::new() }
+    }
+
+    /// Records the direction the snake moves in during the next tick.
+    ///
+    /// Pass the [heading](Game::snake_heading) after turning the snake and before
+    /// [moving](Game::move_snake) it, so refused turns aren't recorded. Since the heading is
+    /// already remapped by the [control transform](crate::ControlTransform), it is played back
+    /// as is.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{ControlTransform, Game, replay::Replay, snake::{Direction, Direction8}};
+    /// let new_game = || {
+    ///     let mut game = Game::<10, 10>::builder().seed(5).build().unwrap();
+    ///     game.set_control_transform(ControlTransform::RotateCw);
+    ///     game
+    /// };
+    ///
+    /// let mut game = new_game();
+    /// let mut replay = Replay::new();
+    /// for dir in [Direction::Left, Direction::Up, Direction::Up, Direction::Right] {
+    ///     game.turn_snake(dir);
+    ///     replay.record(game.snake_heading());
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(replay.inputs()[0], Direction8::Up);
+    ///
+    /// let mut ghost = replay.cursor(new_game());
+    /// while ghost.advance() {}
+    /// assert_eq!(ghost.head(), game.snake_head());
+    /// assert!(ghost.body().eq(game.snake_body()));
+    /// ```
+    ///
+    /// Diagonal headings are recorded as well:
+    /// ```
+    /// use rust_snake::{Game, replay::Replay, snake::Direction8};
+    /// let new_game = || Game::<10, 10>::builder().diagonal().seed(2).build().unwrap();
+    ///
+    /// let mut game = new_game();
+    /// let mut replay = Replay::new();
+    /// for dir in [Direction8::Up, Direction8::UpLeft, Direction8::UpLeft, Direction8::Left] {
+    ///     game.turn_snake8(dir);
+    ///     replay.record(game.snake_heading());
+    ///     game.move_snake();
+    /// }
+    /// assert_eq!(replay.inputs()[1], Direction8::UpLeft);
+    ///
+    /// let mut ghost = replay.cursor(new_game());
+    /// while ghost.advance() {}
+    /// assert_eq!(ghost.head(), game.snake_head());
+    /// assert!(ghost.body().eq(game.snake_body()));
+    /// ```
+    pub fn record(&mut self, dir: snake::Direction8) {
+        self.inputs.push(dir);
+    }
+
+    /// The recorded directions, from the first tick to the last one.
+    pub fn inputs(&self) -> &[snake::Direction8] {
+        &self.inputs
+    }
+
+    /// Returns a cursor playing the replay back on the given game, which should be configured and
+    /// seeded like the recorded one.
+    pub fn cursor<const W: usize, const H: usize>(
+        &self,
+        game: Game<W, H>,
+    ) -> ReplayCursor<'_, W, H> {
+        ReplayCursor {
+            inputs: &self.inputs,
+            game,
+            tick: 0,
+        }
+    }
+
+    /// Plays the replay back on a [seeded](Game::new_seeded) game with the default
+    /// configuration, yielding the map after every tick.
+    ///
+    /// The frames are computed lazily, e.g. to export the replay one image at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Map, replay::Replay, snake::Direction};
+    /// let mut game = Game::<10, 10>::new_seeded(3);
+    /// let mut replay = Replay::new();
+    /// for dir in [Direction::Left, Direction::Up, Direction::Up, Direction::Right] {
+    ///     game.turn_snake(dir);
+    ///     replay.record(game.snake_heading());
+    ///     game.move_snake();
+    /// }
+    ///
+    /// let frames: Vec<Map<10, 10>> = replay.frames(3).collect();
+    /// assert_eq!(frames.len(), 4);
+    /// assert!(frames.last() == Some(game.map()));
+    ///
+    /// let mut cursor = replay.cursor(Game::<10, 10>::new_seeded(3));
+    /// while cursor.advance() {}
+    /// assert!(frames.last() == Some(cursor.game().map()));
+    /// ```
+    pub fn frames<const W: usize, const H: usize>(
+        &self,
+        seed: u64,
+    ) -> impl Iterator<Item = map::Map<W, H>> + '_ {
+        let mut cursor = self.cursor(Game::new_seeded(seed));
+        std::iter::from_fn(move || cursor.advance().then(|| cursor.game().map().clone()))
+    }
+}
+
+/// Plays a [Replay] back one tick at a time, created through [Replay::cursor].
+///
+/// The played back game is separate from any live game, so a frontend can draw its snake as a
+/// ghost without it affecting collisions.
+pub struct ReplayCursor<'a, const W: usize, const H: usize> {
+    inputs: &'a [snake::Direction8],
+    game: Game<W, H>,
+    tick: usize,
+}
+
+impl<'a, const W: usize, const H: usize> ReplayCursor<'a, W, H> {
+    /// Plays back the next tick.
+    ///
+    /// Returns `false` once every recorded tick was played back.
+    pub fn advance(&mut self) -> bool {
+        match self.inputs.get(self.tick) {
+            Some(&dir) => {
+                // The recorded directions were already remapped by the control transform, which
+                // doesn't apply to diagonal ones.
+                match dir.orthogonal() {
+                    Some(dir) => self.game.turn_snake_untransformed(dir),
+                    None => self.game.turn_snake8(dir),
+                }
+                self.game.move_snake();
+                self.tick += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The number of ticks played back so far.
+    pub fn tick(&self) -> usize {
+        self.tick
+    }
+
+    /// The location of the ghost snake's head, see [Game::snake_head].
+    pub fn head(&self) -> (isize, isize) {
+        self.game.snake_head()
+    }
+
+    /// Iterates over the locations of the ghost snake, see [Game::snake_body].
+    pub fn body(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.game.snake_body()
+    }
+
+    /// The played back game.
+    pub fn game(&self) -> &Game<W, H> {
+        &self.game
+    }
+}
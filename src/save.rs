@@ -0,0 +1,312 @@
+//! Saving games to files and loading them again.
+//!
+//! Only available with the `save` feature. Games are saved as JSON, or in a compact binary
+//! format with the `binary` feature.
+
+use crate::{map, snake, Game, GameOverReason, PartsError, State};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// The file formats a [Game] can be saved in.
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub enum SaveFormat {
+    /// Human readable JSON.
+    #[default]
+    Json,
+    /// A compact binary encoding through [bincode](https://docs.rs/bincode), only available with
+    /// the `binary` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, save::SaveFormat};
+    /// let name = format!("rust_snake_binary_{}.bin", std::process::id());
+    /// let path = std::env::temp_dir().join(name);
+    /// let game = Game::<10, 10>::new();
+    /// game.save_to_path_as(&path, SaveFormat::Binary).unwrap();
+    ///
+    /// let loaded = Game::<10, 10>::load_from_path_as(&path, SaveFormat::Binary).unwrap();
+    /// # std::fs::remove_file(&path).unwrap();
+    /// assert!(loaded.map() == game.map());
+    /// ```
+    #[cfg(feature = "binary")]
+    Binary,
+}
+
+/// The reasons saving or loading a [Game] can fail.
+#[derive(Debug)]
+pub enum SaveError {
+    /// Reading or writing the file failed.
+    Io(io::Error),
+    /// The file isn't valid JSON or doesn't contain a game.
+    Json(serde_json::Error),
+    /// The file isn't a valid binary encoding of a game.
+    #[cfg(feature = "binary")]
+    Binary(bincode::Error),
+    /// The saved game has the given width and height, which differ from the loading game's.
+    Dimensions(usize, usize),
+    /// The saved map contains an unknown tile.
+    InvalidMap,
+    /// The saved snake doesn't match the saved map.
+    Parts(PartsError),
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(err) => write!(f, "accessing the save file failed: {err}"),
+            SaveError::Json(err) => write!(f, "invalid JSON save: {err}"),
+            #[cfg(feature = "binary")]
+            SaveError::Binary(err) => write!(f, "invalid binary save: {err}"),
+            SaveError::Dimensions(width, height) => {
+                write!(f, "the saved map has the wrong size {width}x{height}")
+            }
+            SaveError::InvalidMap => write!(f, "the saved map contains an unknown tile"),
+            SaveError::Parts(err) => write!(f, "inconsistent save: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SaveError::Io(err) => Some(err),
+            SaveError::Json(err) => Some(err),
+            #[cfg(feature = "binary")]
+            SaveError::Binary(err) => Some(err),
+            SaveError::Parts(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SaveError {
+    fn from(err: io::Error) -> Self {
+        SaveError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Json(err)
+    }
+}
+
+#[cfg(feature = "binary")]
+impl From<bincode::Error> for SaveError {
+    fn from(err: bincode::Error) -> Self {
+        SaveError::Binary(err)
+    }
+}
+
+/// The saved parts of a game, generic over owning or borrowing the snake.
+#[derive(Serialize, Deserialize)]
+struct SavedGame<S> {
+    width: usize,
+    height: usize,
+    /// The map, [encoded](map::Map::to_flat) as one byte per tile.
+    map: Vec<u8>,
+    snake: S,
+    state: State,
+    game_over_reason: Option<GameOverReason>,
+    score: usize,
+    foods_eaten: usize,
+    boosts: usize,
+    ticks: u64,
+    last_food_tick: u64,
+    hunger: u64,
+    /// The food locations, including food hidden under the snake's tail.
+    food: Vec<(usize, usize)>,
+    /// The ticks the food was spawned at, as a list since JSON only has string keys.
+    food_spawn_ticks: Vec<((usize, usize), u64)>,
+    invincible_ticks: u32,
+    max_lives: u32,
+    lives: u32,
+}
+
+impl<const W: usize, const H: usize> Game<W, H> {
+    /// Saves the game to the file at the given path as JSON, replacing the file if it exists.
+    ///
+    /// The map, the snake, the state and why the game is over, the score, the
+    /// [ticks](Game::ticks), the food, including food hidden under the tail, the remaining
+    /// [lives](Game::lives) and invincibility are saved. Apart from the number of lives, the
+    /// configuration isn't, since it may contain functions, like the
+    /// [food value](crate::builder::GameBuilder::food_value).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, snake::Direction};
+    /// let name = format!("rust_snake_save_{}.json", std::process::id());
+    /// let path = std::env::temp_dir().join(name);
+    /// let mut game = Game::<10, 10>::new_seeded(8);
+    /// game.set_tile(2, 2, Tile::Wall);
+    /// game.set_tile(8, 8, Tile::Food);
+    /// for dir in [Direction::Up, Direction::Right, Direction::Right] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// game.save_to_path(&path).unwrap();
+    /// let loaded = Game::<10, 10>::load_from_path(&path).unwrap();
+    /// # std::fs::remove_file(&path).unwrap();
+    ///
+    /// assert!(loaded.map() == game.map());
+    /// assert!(loaded.snake_body().eq(game.snake_body()));
+    /// assert_eq!(loaded.snake_direction(), game.snake_direction());
+    /// assert_eq!(loaded.state, game.state);
+    /// assert_eq!(loaded.score(), game.score());
+    /// assert_eq!(loaded.ticks(), game.ticks());
+    /// assert_eq!(loaded.food(), game.food());
+    /// assert_eq!(loaded.food_age(8, 8), game.food_age(8, 8));
+    /// assert_eq!(loaded.ticks_since_food(), game.ticks_since_food());
+    /// ```
+    pub fn save_to_path(&self, path: impl AsRef<Path>) -> Result<(), SaveError> {
+        self.save_to_path_as(path, SaveFormat::Json)
+    }
+
+    /// Loads a game [saved](Game::save_to_path) as JSON from the file at the given path.
+    ///
+    /// The loaded game has the default configuration, like [Game::from_parts]. Games which ended
+    /// with the snake's head outside the map can be loaded as well.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, MoveOutcome, snake::Direction};
+    /// let name = format!("rust_snake_hidden_food_{}.json", std::process::id());
+    /// let path = std::env::temp_dir().join(name);
+    /// let mut game = Game::<2, 2>::builder()
+    ///     .initial_size(4)
+    ///     .food_count(0)
+    ///     .food_under_tail()
+    ///     .build()
+    ///     .unwrap();
+    /// for dir in [Direction::Up, Direction::Left, Direction::Down] {
+    ///     game.turn_snake(dir);
+    ///     game.move_snake();
+    /// }
+    /// game.create_food();
+    ///
+    /// game.save_to_path(&path).unwrap();
+    /// let mut loaded = Game::<2, 2>::load_from_path(&path).unwrap();
+    /// # std::fs::remove_file(&path).unwrap();
+    ///
+    /// // The food hidden under the tail is still there.
+    /// assert_eq!(loaded.food(), &[(1, 1)]);
+    /// loaded.turn_snake(Direction::Right);
+    /// loaded.move_snake();
+    /// assert_eq!(loaded.last_outcome(), MoveOutcome::Ate);
+    ///
+    /// // The snake died leaving the map.
+    /// let mut game = Game::<10, 10>::builder().food_count(0).lives(2).build().unwrap();
+    /// while game.lives() > 0 {
+    ///     game.set_paused(false);
+    ///     game.turn_snake(Direction::Down);
+    ///     game.move_snake();
+    /// }
+    ///
+    /// game.save_to_path(&path).unwrap();
+    /// let loaded = Game::<10, 10>::load_from_path(&path).unwrap();
+    /// # std::fs::remove_file(&path).unwrap();
+    /// assert_eq!(loaded.game_over_reason(), Some(GameOverReason::OutOfBounds));
+    /// assert_eq!(loaded.snake_head(), game.snake_head());
+    /// assert_eq!(loaded.lives(), 0);
+    /// ```
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self, SaveError> {
+        Game::load_from_path_as(path, SaveFormat::Json)
+    }
+
+    /// Like [Game::save_to_path], but in the given format.
+    pub fn save_to_path_as(
+        &self,
+        path: impl AsRef<Path>,
+        format: SaveFormat,
+    ) -> Result<(), SaveError> {
+        let saved = SavedGame {
+            width: W,
+            height: H,
+            map: self.map.to_flat(),
+            snake: &self.snake,
+            state: self.state,
+            game_over_reason: self.game_over_reason,
+            score: self.score,
+            foods_eaten: self.foods_eaten,
+            boosts: self.boosts,
+            ticks: self.ticks,
+            last_food_tick: self.last_food_tick,
+            hunger: self.hunger,
+            food: self.food.clone(),
+            food_spawn_ticks: self
+                .food_spawn_ticks
+                .iter()
+                .map(|(&l, &t)| (l, t))
+                .collect(),
+            invincible_ticks: self.invincible_ticks,
+            max_lives: self.max_lives,
+            lives: self.lives,
+        };
+
+        let mut writer = BufWriter::new(File::create(path)?);
+        match format {
+            SaveFormat::Json => serde_json::to_writer(&mut writer, &saved)?,
+            #[cfg(feature = "binary")]
+            SaveFormat::Binary => bincode::serialize_into(&mut writer, &saved)?,
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Like [Game::load_from_path], but in the given format.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, save::{SaveError, SaveFormat}};
+    /// let name = format!("rust_snake_load_{}.json", std::process::id());
+    /// let path = std::env::temp_dir().join(name);
+    /// Game::<10, 10>::new().save_to_path(&path).unwrap();
+    ///
+    /// let err = Game::<8, 8>::load_from_path_as(&path, SaveFormat::Json).err();
+    /// # std::fs::remove_file(&path).unwrap();
+    /// assert!(matches!(err, Some(SaveError::Dimensions(10, 10))));
+    /// ```
+    pub fn load_from_path_as(
+        path: impl AsRef<Path>,
+        format: SaveFormat,
+    ) -> Result<Self, SaveError> {
+        let reader = BufReader::new(File::open(path)?);
+        let saved: SavedGame<snake::Snake> = match format {
+            SaveFormat::Json => serde_json::from_reader(reader)?,
+            #[cfg(feature = "binary")]
+            SaveFormat::Binary => bincode::deserialize_from(reader)?,
+        };
+
+        if (saved.width, saved.height) != (W, H) {
+            return Err(SaveError::Dimensions(saved.width, saved.height));
+        }
+        let map = map::Map::from_flat(&saved.map).ok_or(SaveError::InvalidMap)?;
+        // Food is either visible or hidden under the snake.
+        if let Some(&(x, y)) = saved.food.iter().find(|&&(x, y)| {
+            !map.in_bounds(x, y) || !matches!(map.get(x, y), map::Tile::Food | map::Tile::Snake)
+        }) {
+            return Err(SaveError::Parts(PartsError::InvalidFood(x, y)));
+        }
+
+        let mut game = Game::from_parts(map, saved.snake, saved.state).map_err(SaveError::Parts)?;
+        game.score = saved.score;
+        game.foods_eaten = saved.foods_eaten;
+        game.boosts = saved.boosts;
+        game.ticks = saved.ticks;
+        game.last_food_tick = saved.last_food_tick;
+        game.hunger = saved.hunger;
+        game.food = saved.food;
+        game.food_spawn_ticks = saved.food_spawn_ticks.into_iter().collect();
+        game.game_over_reason = saved.game_over_reason;
+        game.invincible_ticks = saved.invincible_ticks;
+        game.max_lives = saved.max_lives;
+        game.lives = saved.lives;
+
+        Ok(game)
+    }
+}
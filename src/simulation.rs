@@ -0,0 +1,94 @@
+//! Running games without user input or rendering, e.g. to evaluate strategies.
+
+use crate::{snake, Game, GameOverReason, MoveOutcome, State};
+
+/// The result of a [simulated](Game::simulate) game.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct SimResult {
+    /// The [score](Game::score) at the end of the simulation.
+    pub score: usize,
+    /// The number of [ticks](Game::ticks) simulated.
+    pub ticks: u64,
+    /// Why the game ended or `None` if the tick limit was reached first.
+    pub reason: Option<GameOverReason>,
+}
+
+impl<const W: usize, const H: usize> Game<W, H> {
+    /// Runs the game until it is over or `max_ticks` moves were made, turning the snake according
+    /// to the given policy before every move.
+    ///
+    /// The game is [resumed](Game::set_paused) first. Since the game's random number generator is
+    /// used, [seeded](crate::builder::GameBuilder::seed) games with the same policy always produce
+    /// the same result.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, GameOverReason, snake::Direction};
+    /// let run = || {
+    ///     let mut game = Game::<10, 10>::builder().seed(3).build().unwrap();
+    ///     game.simulate(|_| Direction::Up, 100)
+    /// };
+    ///
+    /// let result = run();
+    /// // The head starts at (5, 5), so the sixth move leaves the map.
+    /// assert_eq!(result.ticks, 6);
+    /// assert_eq!(result.reason, Some(GameOverReason::OutOfBounds));
+    /// assert_eq!(result, run());
+    /// ```
+    pub fn simulate<F>(&mut self, mut policy: F, max_ticks: u64) -> SimResult
+    where
+        F: FnMut(&Game<W, H>) -> snake::Direction,
+    {
+        self.set_paused(false);
+
+        let start = self.ticks();
+        while self.state != State::GameOver && self.ticks() - start < max_ticks {
+            let dir = policy(self);
+            self.turn_snake(dir);
+            self.move_snake();
+        }
+
+        SimResult {
+            score: self.score(),
+            ticks: self.ticks() - start,
+            reason: self.game_over_reason(),
+        }
+    }
+
+    /// Makes up to `ticks` [moves](Game::move_snake), stopping early once the game is over.
+    ///
+    /// Before every move, the snake is [turned](Game::turn_snake) in the direction returned by the
+    /// policy, unless it returns `None`. Returns the outcome of the last move made, or
+    /// [MoveOutcome::Idle] if there was none.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, MoveOutcome, snake::Direction};
+    /// let mut game = Game::<10, 10>::builder().food_count(0).build().unwrap();
+    /// assert_eq!(game.advance(3, |_| Some(Direction::Up)), MoveOutcome::Moved);
+    /// assert_eq!(game.snake_head(), (5, 2));
+    ///
+    /// // The snake leaves the map on the third move.
+    /// assert_eq!(game.advance(10, |_| None), MoveOutcome::Died);
+    /// assert_eq!(game.ticks(), 6);
+    /// ```
+    pub fn advance<F>(&mut self, ticks: u64, mut policy: F) -> MoveOutcome
+    where
+        F: FnMut(&Game<W, H>) -> Option<snake::Direction>,
+    {
+        let mut outcome = MoveOutcome::Idle;
+        for _ in 0..ticks {
+            if self.state == State::GameOver {
+                break;
+            }
+
+            if let Some(dir) = policy(self) {
+                self.turn_snake(dir);
+            }
+            self.move_snake();
+            outcome = self.last_outcome();
+        }
+
+        outcome
+    }
+}
@@ -1,8 +1,12 @@
 use crate::map;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 
 /// The four directions the [Snake] can face and `None` in case of a new snake.
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 #[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Direction {
     Left,
     Right,
@@ -12,6 +16,16 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// The four directions the snake can move in, without [None](Direction::None).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction;
+    /// assert!(!Direction::ALL.contains(&Direction::None));
+    /// ```
+    pub const ALL: [Direction; 4] =
+        [Direction::Left, Direction::Right, Direction::Up, Direction::Down];
+
     /// The change on the x-axis.
     ///
     /// # Examples
@@ -55,14 +69,365 @@ impl Direction {
     pub fn opposite(&self, dir: Direction) -> bool {
         self.x() + dir.x() == 0 && self.y() + dir.y() == 0
     }
+
+    /// Returns the `Direction` [opposing](Direction::opposite) this one.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction;
+    ///
+    /// assert_eq!(Direction::Up.inverse(), Direction::Down);
+    /// assert_eq!(Direction::None.inverse(), Direction::None);
+    /// ```
+    pub fn inverse(&self) -> Direction {
+        match self {
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::None => Direction::None,
+        }
+    }
+
+    /// Returns the `Direction` rotated by 90 degrees clockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction;
+    ///
+    /// assert_eq!(Direction::Up.rotate_cw(), Direction::Right);
+    /// assert_eq!(Direction::Left.rotate_cw(), Direction::Up);
+    /// ```
+    pub fn rotate_cw(&self) -> Direction {
+        match self {
+            Direction::Left => Direction::Up,
+            Direction::Right => Direction::Down,
+            Direction::Up => Direction::Right,
+            Direction::Down => Direction::Left,
+            Direction::None => Direction::None,
+        }
+    }
+
+    /// Returns the `Direction` rotated by 90 degrees counterclockwise.
+    pub fn rotate_ccw(&self) -> Direction {
+        self.rotate_cw().inverse()
+    }
+
+    /// Resolves an analog input, e.g. from a gamepad stick or a swipe, into a `Direction`.
+    ///
+    /// The axis with the larger magnitude wins, preferring the x-axis on ties. Like
+    /// [Direction::y], positive values on the y-axis point down. If the winning direction would
+    /// reverse the `current` one, the other axis is used instead, if it has any input.
+    ///
+    /// Returns [Option::None] if there is no usable input.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction;
+    /// assert_eq!(Direction::from_axis(0.9, -0.4, Direction::Up), Some(Direction::Right));
+    /// assert_eq!(Direction::from_axis(0.2, -0.7, Direction::Left), Some(Direction::Up));
+    ///
+    /// // Pushing back against the heading falls back to the weaker axis.
+    /// assert_eq!(Direction::from_axis(-0.9, 0.3, Direction::Right), Some(Direction::Down));
+    /// assert_eq!(Direction::from_axis(-0.9, 0.0, Direction::Right), None);
+    /// assert_eq!(Direction::from_axis(0.0, 0.0, Direction::None), None);
+    /// ```
+    pub fn from_axis(dx: f32, dy: f32, current: Direction) -> Option<Direction> {
+        let horizontal = if dx < 0.0 { Direction::Left } else { Direction::Right };
+        let vertical = if dy < 0.0 { Direction::Up } else { Direction::Down };
+
+        let candidates = if dx.abs() >= dy.abs() {
+            [(dx, horizontal), (dy, vertical)]
+        } else {
+            [(dy, vertical), (dx, horizontal)]
+        };
+
+        candidates.into_iter()
+            .find(|&(input, dir)| input != 0.0 && !current.opposite(dir))
+            .map(|(_, dir)| dir)
+    }
+
+    /// Returns the `Direction` stepping from one location to an orthogonally adjacent one, or
+    /// [Option::None] if they aren't adjacent.
+    ///
+    /// This doesn't know about wrapping, see
+    /// [Game::adjacent_direction](crate::Game::adjacent_direction) for that.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction;
+    /// assert_eq!(Direction::between((1, 1), (0, 1)), Some(Direction::Left));
+    /// assert_eq!(Direction::between((1, 1), (2, 1)), Some(Direction::Right));
+    /// assert_eq!(Direction::between((1, 1), (1, 0)), Some(Direction::Up));
+    /// assert_eq!(Direction::between((1, 1), (1, 2)), Some(Direction::Down));
+    ///
+    /// assert_eq!(Direction::between((1, 1), (2, 2)), None);
+    /// assert_eq!(Direction::between((1, 1), (1, 1)), None);
+    /// assert_eq!(Direction::between((0, 1), (3, 1)), None);
+    /// ```
+    pub fn between(from: (usize, usize), to: (usize, usize)) -> Option<Direction> {
+        Direction::from_delta(
+            to.0 as isize - from.0 as isize,
+            to.1 as isize - from.1 as isize,
+        )
+    }
+
+    /// Returns the `Direction` moving by the given change on the x- and y-axis, if there is one.
+    pub(crate) fn from_delta(dx: isize, dy: isize) -> Option<Direction> {
+        match (dx, dy) {
+            (-1, 0) => Some(Direction::Left),
+            (1, 0) => Some(Direction::Right),
+            (0, -1) => Some(Direction::Up),
+            (0, 1) => Some(Direction::Down),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction::Left => "Left",
+            Direction::Right => "Right",
+            Direction::Up => "Up",
+            Direction::Down => "Down",
+            Direction::None => "None",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parses the name of a `Direction`, as it is [displayed](fmt::Display).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction;
+    /// assert_eq!("Up".parse(), Ok(Direction::Up));
+    /// assert_eq!(Direction::Left.to_string().parse(), Ok(Direction::Left));
+    /// assert!("up".parse::<Direction>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Left" => Ok(Direction::Left),
+            "Right" => Ok(Direction::Right),
+            "Up" => Ok(Direction::Up),
+            "Down" => Ok(Direction::Down),
+            "None" => Ok(Direction::None),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+/// The error returned when parsing an unknown [Direction], holding the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ParseDirectionError(pub String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown direction \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// The eight directions the [Snake] can move in a game with
+/// [diagonal movement](crate::builder::GameBuilder::diagonal), and `None`.
+#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq)]
+pub enum Direction8 {
+    Left,
+    Right,
+    Up,
+    Down,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+    None
+}
+
+impl Direction8 {
+    /// The change on the x-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction8;
+    /// assert_eq!(Direction8::UpLeft.x(), -1);
+    /// assert_eq!(Direction8::Down.x(), 0);
+    /// ```
+    pub fn x(&self) -> isize {
+        match self {
+            Direction8::Left | Direction8::UpLeft | Direction8::DownLeft => -1,
+            Direction8::Right | Direction8::UpRight | Direction8::DownRight => 1,
+            _ => 0
+        }
+    }
+
+    /// The change on the y-axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction8;
+    /// assert_eq!(Direction8::UpLeft.y(), -1);
+    /// assert_eq!(Direction8::Right.y(), 0);
+    /// ```
+    pub fn y(&self) -> isize {
+        match self {
+            Direction8::Up | Direction8::UpLeft | Direction8::UpRight => -1,
+            Direction8::Down | Direction8::DownLeft | Direction8::DownRight => 1,
+            _ => 0
+        }
+    }
+
+    /// Returns if this and the given `Direction8` oppose one another.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Direction8;
+    /// assert!(Direction8::UpLeft.opposite(Direction8::DownRight));
+    /// assert!(!Direction8::UpLeft.opposite(Direction8::Right));
+    /// ```
+    pub fn opposite(&self, dir: Direction8) -> bool {
+        self.x() + dir.x() == 0 && self.y() + dir.y() == 0
+    }
+
+    /// Returns the `Direction8` [opposing](Direction8::opposite) this one.
+    pub fn inverse(&self) -> Direction8 {
+        match self {
+            Direction8::Left => Direction8::Right,
+            Direction8::Right => Direction8::Left,
+            Direction8::Up => Direction8::Down,
+            Direction8::Down => Direction8::Up,
+            Direction8::UpLeft => Direction8::DownRight,
+            Direction8::UpRight => Direction8::DownLeft,
+            Direction8::DownLeft => Direction8::UpRight,
+            Direction8::DownRight => Direction8::UpLeft,
+            Direction8::None => Direction8::None,
+        }
+    }
+
+    /// Returns the matching [Direction], or [Option::None] if this one is diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::{Direction, Direction8};
+    /// assert_eq!(Direction8::Up.orthogonal(), Some(Direction::Up));
+    /// assert_eq!(Direction8::None.orthogonal(), Some(Direction::None));
+    /// assert_eq!(Direction8::UpLeft.orthogonal(), None);
+    /// ```
+    pub fn orthogonal(&self) -> Option<Direction> {
+        match self {
+            Direction8::Left => Some(Direction::Left),
+            Direction8::Right => Some(Direction::Right),
+            Direction8::Up => Some(Direction::Up),
+            Direction8::Down => Some(Direction::Down),
+            Direction8::None => Some(Direction::None),
+            _ => None
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+            Direction::None => Direction8::None,
+        }
+    }
+}
+
+/// The errors of fallible [Snake] operations.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum SnakeError {
+    /// The snake's head at the given location is out of bounds.
+    OutOfBounds(isize, isize),
+}
+
+impl fmt::Display for SnakeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnakeError::OutOfBounds(x, y) => write!(f, "snake head at ({x}, {y}) is out of bounds"),
+        }
+    }
+}
+
+impl std::error::Error for SnakeError {}
+
+/// An iterator over the locations of a [Snake], from the oldest tail piece to the head, see
+/// [Snake::body_view].
+///
+/// It borrows the tail, so nothing is copied or allocated.
+#[derive(Clone, Debug)]
+pub struct BodyView<'a> {
+    tail: std::slice::Iter<'a, (usize, usize)>,
+    head: Option<(usize, usize)>,
+}
+
+impl Iterator for BodyView<'_> {
+    type Item = (usize, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tail.next().copied().or_else(|| self.head.take())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.tail.len() + self.head.is_some() as usize;
+        (len, Some(len))
+    }
 }
 
+impl ExactSizeIterator for BodyView<'_> {}
+
 /// Struct representing the snake.
+///
+/// With the `serde` feature, the snake can be serialized on its own, e.g. to send it over the
+/// network, and put back on a map through [Game::from_snake](crate::Game::from_snake).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SnakeParts"))]
 pub struct Snake {
     head: (isize, isize),
     dir: Direction,
     pub size: usize,
     tail: Vec<(usize, usize)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    occupied: HashMap<(usize, usize), usize>,
+}
+
+/// The serialized parts of a [Snake], which the occupied locations follow from.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SnakeParts {
+    head: (isize, isize),
+    dir: Direction,
+    size: usize,
+    tail: Vec<(usize, usize)>,
+}
+
+#[cfg(feature = "serde")]
+impl From<SnakeParts> for Snake {
+    fn from(parts: SnakeParts) -> Self {
+        let mut snake = Snake {
+            head: parts.head,
+            dir: parts.dir,
+            size: parts.size,
+            tail: parts.tail,
+            occupied: HashMap::new(),
+        };
+        for &location in &snake.tail {
+            *snake.occupied.entry(location).or_insert(0) += 1;
+        }
+        snake.occupy_head();
+
+        snake
+    }
 }
 
 impl Snake {
@@ -73,6 +438,7 @@ impl Snake {
             dir: Direction::None,
             size,
             tail: Vec::new(),
+            occupied: HashMap::from([((x, y), 1)]),
         }
     }
 
@@ -82,6 +448,9 @@ impl Snake {
     /// The y coordinate of the `Snake`'s head.
     pub fn y(&self) -> isize { self.head.1 }
 
+    /// The [Direction] the `Snake` is facing.
+    pub fn direction(&self) -> Direction { self.dir }
+
     /// Sets the `Snake`'s direction to the given one if it doesn't [oppose](Direction::opposite()) the current one.
     pub fn turn(&mut self, dir: Direction) {
         if !self.dir.opposite(dir) {
@@ -89,28 +458,283 @@ impl Snake {
         }
     }
 
+    /// Sets the `Snake`'s direction, even if it [opposes](Direction::opposite()) the current one.
+    ///
+    /// Unlike [turn](Snake::turn), which handles the player's input, this is meant for setting up
+    /// the snake and for the game's own movement rules.
+    pub(crate) fn set_direction(&mut self, dir: Direction) {
+        self.dir = dir;
+    }
+
     /// Moves the snake one space forward and appends it's previous location to the tail.
     ///
     /// The snake may end up [out of bounds](Snake::in_bounds) afterwards.
     pub fn forward(&mut self) {
+        // Move in the current direction.
+        let (x, y) = self.next();
+        self.forward_to(x, y);
+    }
+
+    /// Moves the snake's head to location `(x,y)` and appends it's previous location to the tail.
+    ///
+    /// This is used instead of [forward](Snake::forward) when the snake doesn't end up on the next
+    /// location, e.g. when passing through [one-way](map::Tile::OneWay) tiles or wrapping around
+    /// the map.
+    pub fn forward_to(&mut self, x: isize, y: isize) {
         // Add head to the tail and put snake tile on the map.
         self.tail.push((self.x() as usize, self.y() as usize));
 
-        // Move in the current direction.
-        self.head.0 += self.dir.x();
-        self.head.1 += self.dir.y();
+        self.head = (x, y);
+        self.occupy_head();
+    }
+
+    /// Reverses the snake, so the oldest tail piece becomes the head and the head becomes the
+    /// newest tail piece.
+    ///
+    /// The snake will face away from the rest of its body, continuing the path of the tail
+    /// backwards. If that isn't possible, e.g. because the snake has no tail, it will face the
+    /// [inverse](Direction::inverse) direction instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::{Direction, Snake};
+    /// let mut snake = Snake::new(0, 0, 3);
+    /// snake.turn(Direction::Right);
+    /// snake.forward();
+    /// snake.turn(Direction::Down);
+    /// snake.forward();
+    ///
+    /// snake.reverse();
+    /// assert_eq!((snake.x(), snake.y()), (0, 0));
+    /// assert_eq!(snake.direction(), Direction::Left);
+    /// assert_eq!(snake.body().collect::<Vec<_>>(), vec![(1, 1), (1, 0), (0, 0)]);
+    /// ```
+    pub fn reverse(&mut self) {
+        if self.tail.is_empty() {
+            self.dir = self.dir.inverse();
+            return;
+        }
+
+        let end = self.tail.remove(0);
+        self.tail.push((self.x() as usize, self.y() as usize));
+        self.tail.reverse();
+
+        let (next_x, next_y) = self.tail[self.tail.len() - 1];
+        self.dir = Direction::from_delta(
+            end.0 as isize - next_x as isize,
+            end.1 as isize - next_y as isize,
+        )
+        .unwrap_or_else(|| self.dir.inverse());
+        self.head = (end.0 as isize, end.1 as isize);
+    }
+
+    /// The location the head will be moved to by the next call to [forward](Snake::forward).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::{Direction, Snake};
+    /// let mut snake = Snake::new(0, 0, 3);
+    /// snake.turn(Direction::Up);
+    /// assert_eq!(snake.next(), (0, -1));
+    /// ```
+    pub fn next(&self) -> (isize, isize) {
+        (self.x() + self.dir.x(), self.y() + self.dir.y())
     }
 
     /// Removes the last tail piece, if the tail reached the snake size.
     ///
-    /// The tail piece will also be removed from the passed [Map](map::Map).
+    /// The tail piece will also be removed from the passed [Map](map::Map), unless another part of
+    /// the snake still occupies its location.
     pub fn cut_tail<const W: usize, const H: usize>(&mut self, map: &mut map::Map<W, H>) {
-        if self.tail.len() >= self.size {
+        if !self.tail.is_empty() && self.tail.len() >= self.size {
             let (x, y) = self.tail.remove(0);
-            map.set(x, y, map::Tile::Empty);
+
+            // The location may still be occupied, e.g. if the head just moved onto it.
+            if self.vacate(x, y) {
+                map.set(x, y, map::Tile::Empty);
+            }
+        }
+    }
+
+    /// Returns the location the next [forward](Snake::forward) and [cut](Snake::cut_tail) will
+    /// vacate, if there is one.
+    pub fn vacated_next(&self) -> Option<(usize, usize)> {
+        if self.tail.len() + 1 < self.size {
+            return None;
+        }
+
+        let end = match self.tail.first() {
+            Some(&end) => end,
+            None => (self.x() as usize, self.y() as usize),
+        };
+        (self.occupied.get(&end) == Some(&1)).then_some(end)
+    }
+
+    /// The location of the oldest tail piece or `None` if the snake has no tail.
+    pub fn tail_end(&self) -> Option<(usize, usize)> { self.tail.first().copied() }
+
+    /// The location of the newest tail piece, directly behind the head, or `None` if the snake has
+    /// no tail.
+    pub fn neck(&self) -> Option<(usize, usize)> { self.tail.last().copied() }
+
+    /// Decreases the size by the given amount and [cuts](Snake::cut_tail) the tail to fit.
+    ///
+    /// Once the size reaches zero, only the head is left.
+    pub fn shrink<const W: usize, const H: usize>(
+        &mut self,
+        amount: usize,
+        map: &mut map::Map<W, H>,
+    ) {
+        self.size = self.size.saturating_sub(amount);
+
+        while !self.tail.is_empty() && self.tail.len() >= self.size {
+            self.cut_tail(map);
+        }
+    }
+
+    /// Keeps track of the head location, unless it left the map on the negative side.
+    fn occupy_head(&mut self) {
+        if 0 <= self.x() && 0 <= self.y() {
+            *self
+                .occupied
+                .entry((self.x() as usize, self.y() as usize))
+                .or_insert(0) += 1;
+        }
+    }
+
+    /// Removes one occupation of the location `(x,y)`.
+    ///
+    /// Returns if the location is no longer occupied by any part of the snake.
+    fn vacate(&mut self, x: usize, y: usize) -> bool {
+        match self.occupied.get_mut(&(x, y)) {
+            Some(count) if *count > 1 => {
+                *count -= 1;
+                false
+            }
+            _ => {
+                self.occupied.remove(&(x, y));
+                true
+            }
+        }
+    }
+
+    /// Iterates over the locations of the snake, from the oldest tail piece to the head.
+    ///
+    /// The head is skipped, if it left the map.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::Map;
+    /// use rust_snake::snake::{Direction, Snake};
+    /// let mut map = Map::<3, 3>::new();
+    /// let mut snake = Snake::new(0, 0, 3);
+    /// snake.turn(Direction::Right);
+    /// snake.forward();
+    /// snake.cut_tail(&mut map);
+    ///
+    /// assert_eq!(snake.body().collect::<Vec<_>>(), vec![(0, 0), (1, 0)]);
+    /// ```
+    pub fn body(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.tail.iter().copied().chain(self.head_on_map())
+    }
+
+    /// Like [body](Snake::body), but returns a [BodyView], which knows its length.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::{Direction, Snake};
+    /// let mut snake = Snake::new(0, 0, 3);
+    /// snake.turn(Direction::Right);
+    /// snake.forward();
+    ///
+    /// let view = snake.body_view();
+    /// assert_eq!(view.len(), 2);
+    /// assert_eq!(view.collect::<Vec<_>>(), vec![(0, 0), (1, 0)]);
+    /// ```
+    #[inline]
+    pub fn body_view(&self) -> BodyView<'_> {
+        BodyView { tail: self.tail.iter(), head: self.head_on_map() }
+    }
+
+    /// The location of the head, unless it left the map.
+    ///
+    /// The head always occupies its location while it is on the map, even if the tail does too.
+    fn head_on_map(&self) -> Option<(usize, usize)> {
+        let head = (self.x() as usize, self.y() as usize);
+        (0 <= self.x() && 0 <= self.y() && self.occupied.contains_key(&head)).then_some(head)
+    }
+
+    /// Vacates the head after it moved past the right or bottom boundary of the map, which the
+    /// snake can't tell apart from a location on a larger map.
+    pub(crate) fn leave_map(&mut self) {
+        if 0 <= self.x() && 0 <= self.y() {
+            self.vacate(self.x() as usize, self.y() as usize);
         }
     }
 
+    /// How tightly the snake is coiled up, between `0` and `1`.
+    ///
+    /// This is the number of locations occupied by the snake divided by the area of the smallest
+    /// square containing all of them. A straight snake of length `n` has a compactness of `1 / n`,
+    /// while a snake coiled up into a square has a compactness of `1`.
+    ///
+    /// Note that a snake which [wrapped](crate::WallBehavior::Wrap) around the map spans the
+    /// map's edges, so it counts as spread out.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::{Direction, Snake};
+    /// let mut straight = Snake::new(0, 0, 4);
+    /// straight.turn(Direction::Right);
+    /// for _ in 0..3 {
+    ///     straight.forward();
+    /// }
+    /// assert_eq!(straight.compactness(), 0.25);
+    ///
+    /// let mut coiled = Snake::new(0, 0, 4);
+    /// for dir in [Direction::Right, Direction::Down, Direction::Left] {
+    ///     coiled.turn(dir);
+    ///     coiled.forward();
+    /// }
+    /// assert_eq!(coiled.compactness(), 1.0);
+    /// ```
+    pub fn compactness(&self) -> f32 {
+        if self.occupied.is_empty() {
+            return 0.0;
+        }
+
+        let (mut min_x, mut min_y) = (usize::MAX, usize::MAX);
+        let (mut max_x, mut max_y) = (0, 0);
+        for &(x, y) in self.occupied.keys() {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        let side = (max_x - min_x).max(max_y - min_y) + 1;
+        self.occupied.len() as f32 / (side * side) as f32
+    }
+
+    /// Returns if the location `(x,y)` is occupied by the head or the tail of the snake.
+    ///
+    /// The occupied locations are tracked alongside the tail, so this is a constant time lookup.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::snake::Snake;
+    /// let snake = Snake::new(2, 3, 3);
+    /// assert!(snake.occupies(2, 3));
+    /// assert!(!snake.occupies(3, 2));
+    /// ```
+    pub fn occupies(&self, x: usize, y: usize) -> bool {
+        self.occupied.contains_key(&(x, y))
+    }
+
+    /// Same as [Snake::occupies].
+    #[deprecated(note = "use `Snake::occupies` instead")]
+    pub fn contains(&self, x: usize, y: usize) -> bool { self.occupies(x, y) }
+
     /// Returns the [Tile] at the location of the snake.
     ///
     /// This call is equivalent to
@@ -123,11 +747,37 @@ impl Snake {
     /// ```
     /// # Panics
     ///
-    /// If the snake is [out of bounds](Snake::in_bounds).
+    /// If the snake is [out of bounds](Snake::in_bounds), see [Snake::try_touching_tile].
     pub fn touching_tile<const W: usize, const H: usize>(&self, map: &map::Map<W, H>) -> map::Tile {
         map.get(self.x() as usize, self.y() as usize)
     }
 
+    /// Returns the [Tile](map::Tile) at the location of the snake or an [Err] if the snake is
+    /// [out of bounds](Snake::in_bounds).
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::map::Map;
+    /// use rust_snake::snake::{Direction, Snake, SnakeError};
+    /// let map = Map::<3, 3>::new();
+    /// let mut snake = Snake::new(0, 0, 3);
+    /// assert!(snake.try_touching_tile(&map).is_ok());
+    ///
+    /// snake.turn(Direction::Left);
+    /// snake.forward();
+    /// assert_eq!(snake.try_touching_tile(&map).err(), Some(SnakeError::OutOfBounds(-1, 0)));
+    /// ```
+    pub fn try_touching_tile<const W: usize, const H: usize>(
+        &self,
+        map: &map::Map<W, H>,
+    ) -> Result<map::Tile, SnakeError> {
+        if !self.in_bounds(map) {
+            return Err(SnakeError::OutOfBounds(self.x(), self.y()));
+        }
+
+        Ok(self.touching_tile(map))
+    }
+
     /// Sets the [Tile] at the location of the snake to a snake tile.
     ///
     /// This call is equivalent to
@@ -140,11 +790,25 @@ impl Snake {
     /// ```
     /// # Panics
     ///
-    /// If the snake is [out of bounds](Snake::in_bounds).
+    /// If the snake is [out of bounds](Snake::in_bounds), see [Snake::try_place_head].
     pub fn place_head<const W: usize, const H: usize>(&self, map: &mut map::Map<W, H>) {
         map.set(self.x() as usize, self.y() as usize, map::Tile::Snake);
     }
 
+    /// Sets the [Tile](map::Tile) at the location of the snake to a snake tile or returns an
+    /// [Err] if the snake is [out of bounds](Snake::in_bounds).
+    pub fn try_place_head<const W: usize, const H: usize>(
+        &self,
+        map: &mut map::Map<W, H>,
+    ) -> Result<(), SnakeError> {
+        if !self.in_bounds(map) {
+            return Err(SnakeError::OutOfBounds(self.x(), self.y()));
+        }
+
+        self.place_head(map);
+        Ok(())
+    }
+
     /// Returns if the snake is inside [Map](map::Map) boundaries.
     ///
     /// The boundaries range from `0`, inclusive,  to the map boundaries, exclusive.
@@ -0,0 +1,280 @@
+//! Rendering of the [Map](map::Map) in a terminal through [crossterm].
+//!
+//! Only available with the `terminal` feature, which is enabled by default.
+
+use crate::{map, Game};
+use crossterm::style::{Color, StyledContent, Stylize};
+use crossterm::{cursor, style, QueueableCommand};
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// The styled content printed for every kind of [Tile](map::Tile) and the border.
+///
+/// Every tile takes up the width of two characters in the default style, so tiles appear square.
+///
+/// # Examples
+/// ```
+/// use crossterm::style::{Color, Stylize};
+/// use rust_snake::terminal::TileStyle;
+/// let style = TileStyle {
+///     snake: "()".with(Color::Green),
+///     food: "<>".with(Color::Yellow),
+///     ..TileStyle::default()
+/// };
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct TileStyle {
+    pub border: StyledContent<&'static str>,
+    pub empty: StyledContent<&'static str>,
+    pub snake: StyledContent<&'static str>,
+    pub food: StyledContent<&'static str>,
+    pub one_way: StyledContent<&'static str>,
+    pub wall: StyledContent<&'static str>,
+}
+
+impl TileStyle {
+    /// Returns the styled content printed for the given [Tile](map::Tile).
+    pub fn tile(&self, tile: map::Tile) -> StyledContent<&'static str> {
+        match tile {
+            map::Tile::Empty => self.empty,
+            map::Tile::Snake => self.snake,
+            map::Tile::Food => self.food,
+            map::Tile::OneWay(_) => self.one_way,
+            map::Tile::Wall => self.wall,
+        }
+    }
+}
+
+impl Default for TileStyle {
+    /// Colored blocks, with a green snake and yellow food on black, in the
+    /// [default colors](map::Tile::default_color) of the tiles.
+    fn default() -> Self {
+        let block = |tile: map::Tile| {
+            let [r, g, b] = tile.default_color();
+            "  ".on(Color::Rgb { r, g, b })
+        };
+
+        TileStyle {
+            border: "  ".on(Color::DarkGrey),
+            empty: block(map::Tile::Empty),
+            snake: block(map::Tile::Snake),
+            food: block(map::Tile::Food),
+            one_way: block(map::Tile::OneWay(crate::snake::Direction::None)),
+            wall: block(map::Tile::Wall),
+        }
+    }
+}
+
+/// The built-in color themes, each providing a [TileStyle].
+///
+/// Themes are parsed from their lowercase names, e.g. to pick one on the command line.
+///
+/// # Examples
+/// ```
+/// use crossterm::style::Color;
+/// use rust_snake::{map::Tile, terminal::{ParseThemeError, Theme}};
+/// assert_eq!("neon".parse(), Ok(Theme::Neon));
+/// assert_eq!(Theme::Mono.to_string().parse(), Ok(Theme::Mono));
+/// assert_eq!(
+///     "rainbow".parse::<Theme>(),
+///     Err(ParseThemeError("rainbow".to_string()))
+/// );
+///
+/// for theme in Theme::all() {
+///     let _ = theme.style();
+/// }
+///
+/// // The classic theme uses the default colors of the tiles.
+/// let [r, g, b] = Tile::Snake.default_color();
+/// let snake = Theme::Classic.style().snake;
+/// assert_eq!(snake.style().background_color, Some(Color::Rgb { r, g, b }));
+/// ```
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub enum Theme {
+    /// The [default](TileStyle::default) style: a green snake and yellow food on black.
+    #[default]
+    Classic,
+    /// Shades of grey only, for terminals with few colors. Food is drawn as `<>`, so it stands
+    /// out from the snake.
+    Mono,
+    /// A cyan snake and magenta food on black, framed in purple.
+    Neon,
+}
+
+impl Theme {
+    /// Returns all themes.
+    pub fn all() -> [Theme; 3] {
+        [Theme::Classic, Theme::Mono, Theme::Neon]
+    }
+
+    /// Returns the styled content printed for every kind of tile in this theme.
+    pub fn style(self) -> TileStyle {
+        match self {
+            Theme::Classic => TileStyle::default(),
+            Theme::Mono => TileStyle {
+                border: "  ".on(Color::DarkGrey),
+                empty: "  ".on(Color::Black),
+                snake: "  ".on(Color::White),
+                food: "<>".with(Color::White).on(Color::Black),
+                one_way: "::".with(Color::Grey).on(Color::Black),
+                wall: "  ".on(Color::Grey),
+            },
+            Theme::Neon => TileStyle {
+                border: "  ".on(Color::DarkMagenta),
+                empty: "  ".on(Color::Black),
+                snake: "  ".on(Color::Cyan),
+                food: "  ".on(Color::Magenta),
+                one_way: "  ".on(Color::Blue),
+                wall: "  ".on(Color::DarkBlue),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Theme::Classic => "classic",
+            Theme::Mono => "mono",
+            Theme::Neon => "neon",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+
+    /// Parses the name of a `Theme`, as it is [displayed](fmt::Display).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "classic" => Ok(Theme::Classic),
+            "mono" => Ok(Theme::Mono),
+            "neon" => Ok(Theme::Neon),
+            _ => Err(ParseThemeError(s.to_string())),
+        }
+    }
+}
+
+/// The error returned when parsing an unknown [Theme], holding the input.
+#[derive(PartialEq, Clone, Debug)]
+pub struct ParseThemeError(pub String);
+
+impl fmt::Display for ParseThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown theme \"{}\"", self.0)
+    }
+}
+
+impl std::error::Error for ParseThemeError {}
+
+/// The layout of the output of [draw].
+///
+/// # Examples
+/// ```
+/// use rust_snake::terminal::{self, Layout, TileStyle};
+/// use rust_snake::Game;
+/// let game = Game::<10, 8>::new();
+/// let layout = Layout {
+///     border: 2,
+///     show_score: true,
+/// };
+///
+/// let mut out = Vec::new();
+/// terminal::draw(&mut out, &game, &TileStyle::default(), &layout).unwrap();
+///
+/// // Lines are separated by moving the cursor to the next line.
+/// let lines = String::from_utf8(out).unwrap().matches("\x1b[1E").count() + 1;
+/// assert_eq!(lines, layout.lines(8));
+/// assert_eq!(lines, 1 + 2 + 8 + 2);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Layout {
+    /// The thickness of the border around the map, in tiles.
+    pub border: usize,
+    /// Whether a line with the [score](crate::Game::score) is printed above the map.
+    pub show_score: bool,
+}
+
+impl Layout {
+    /// Returns the number of lines printed for a map of the given height.
+    pub fn lines(&self, height: usize) -> usize {
+        usize::from(self.show_score) + height + 2 * self.border
+    }
+}
+
+impl Default for Layout {
+    /// A border of one tile and no score line.
+    fn default() -> Self {
+        Layout {
+            border: 1,
+            show_score: false,
+        }
+    }
+}
+
+/// Prints out the game's map at the current cursor position, using the given [TileStyle] and
+/// [Layout].
+///
+/// [Layout::lines] tells how many lines are printed. The cursor is left at the end of the last
+/// line.
+///
+/// Returns an [Err] if a terminal operation fails.
+pub fn draw<const W: usize, const H: usize>(
+    out: &mut impl Write,
+    game: &Game<W, H>,
+    style: &TileStyle,
+    layout: &Layout,
+) -> io::Result<()> {
+    let map = game.map();
+    let width = W + 2 * layout.border;
+    let mut first = true;
+
+    if layout.show_score {
+        next_line(out, &mut first)?;
+        out.queue(style::Print(format!("Score: {}", game.score())))?;
+    }
+
+    for _ in 0..layout.border {
+        next_line(out, &mut first)?;
+        for _ in 0..width {
+            out.queue(style::PrintStyledContent(style.border))?;
+        }
+    }
+
+    // Iterate over the map rows and print them.
+    for y in 0..H {
+        next_line(out, &mut first)?;
+        for _ in 0..layout.border {
+            out.queue(style::PrintStyledContent(style.border))?;
+        }
+
+        for x in 0..W {
+            out.queue(style::PrintStyledContent(style.tile(map.get(x, y))))?;
+        }
+
+        for _ in 0..layout.border {
+            out.queue(style::PrintStyledContent(style.border))?;
+        }
+    }
+
+    for _ in 0..layout.border {
+        next_line(out, &mut first)?;
+        for _ in 0..width {
+            out.queue(style::PrintStyledContent(style.border))?;
+        }
+    }
+
+    out.flush()
+}
+
+/// Moves the cursor to the next line, unless the first line is about to be printed.
+fn next_line(out: &mut impl Write, first: &mut bool) -> io::Result<()> {
+    if !*first {
+        out.queue(cursor::MoveToNextLine(1))?;
+    }
+    *first = false;
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! Stamping common obstacle patterns of [walls](map::Tile::Wall) onto the map of a [Game].
+
+use crate::{map, Game, GameOverReason};
+use std::fmt;
+
+impl<const W: usize, const H: usize> Game<W, H> {
+    /// Surrounds the map with a ring of walls, `inset` tiles away from the boundaries.
+    ///
+    /// Locations occupied by a snake are skipped. Food tiles covered by a wall are placed
+    /// elsewhere.
+    ///
+    /// Returns an [Err] without changing the map if the ring doesn't fit on the map or would cover
+    /// the snake's head.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, walls::WallError};
+    /// let mut game = Game::<10, 10>::new();
+    /// game.add_wall_ring(1).unwrap();
+    ///
+    /// game.display(|map| {
+    ///     assert_eq!(map.count(Tile::Wall), 4 * 7);
+    ///     for x in 2..8 {
+    ///         for y in 2..8 {
+    ///             assert!(map.get(x, y) != Tile::Wall);
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(game.add_wall_ring(5), Err(WallError::Inset(5)));
+    /// ```
+    pub fn add_wall_ring(&mut self, inset: usize) -> Result<(), WallError> {
+        if 2 * inset >= W || 2 * inset >= H {
+            return Err(WallError::Inset(inset));
+        }
+
+        self.add_walls(Self::ring(inset))
+    }
+
+    /// Turns the outermost ring of tiles, which isn't closed yet, into walls, see
+    /// [GameBuilder::arena_shrink_interval](crate::builder::GameBuilder::arena_shrink_interval).
+    ///
+    /// The game is over if the ring catches the snake, unless it is
+    /// [invincible](Game::is_invincible). Locations occupied by a snake are skipped.
+    pub(crate) fn close_arena(&mut self) {
+        let inset = self.arena_inset;
+        if 2 * inset >= W || 2 * inset >= H {
+            return;
+        }
+
+        let mut ring = Self::ring(inset);
+        if !self.is_invincible() && ring.iter().any(|&(x, y)| self.snake.occupies(x, y)) {
+            self.end(GameOverReason::ArenaClosed);
+            return;
+        }
+
+        // An invincible snake and AI snakes are kept out of the wall.
+        ring.retain(|&(x, y)| !self.is_occupied(x, y));
+        self.add_walls(ring)
+            .expect("the ring doesn't cover the head");
+        self.arena_inset += 1;
+
+        if self.food().is_empty() && self.map().count(map::Tile::Empty) == 0 {
+            self.end(GameOverReason::BoardFull);
+        }
+    }
+
+    /// The locations of a ring `inset` tiles away from the boundaries, which must fit on the map.
+    fn ring(inset: usize) -> Vec<(usize, usize)> {
+        let (right, bottom) = (W - 1 - inset, H - 1 - inset);
+        let horizontal = (inset..=right).flat_map(|x| [(x, inset), (x, bottom)]);
+        let vertical = (inset..=bottom).flat_map(|y| [(inset, y), (right, y)]);
+
+        horizontal.chain(vertical).collect()
+    }
+
+    /// Adds walls along the middle row and column of the map, leaving the tiles closer than `gap`
+    /// to the center free.
+    ///
+    /// Locations occupied by a snake are skipped. Food tiles covered by a wall are placed
+    /// elsewhere.
+    ///
+    /// Returns an [Err] without changing the map if the cross would cover the snake's head.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_snake::{Game, map::Tile, walls::WallError};
+    /// let mut game = Game::<10, 10>::new();
+    ///
+    /// // The snake starts in the center.
+    /// assert_eq!(game.add_wall_cross(0), Err(WallError::OverlapsHead));
+    ///
+    /// game.add_wall_cross(3).unwrap();
+    /// assert_eq!(game.display(|map| map.count(Tile::Wall)), 2 * 5);
+    /// ```
+    pub fn add_wall_cross(&mut self, gap: usize) -> Result<(), WallError> {
+        let (cx, cy) = (W / 2, H / 2);
+        let row = (0..W).filter(|x| x.abs_diff(cx) >= gap).map(|x| (x, cy));
+        let column = (0..H).filter(|y| y.abs_diff(cy) >= gap).map(|y| (cx, y));
+
+        self.add_walls(row.chain(column).collect())
+    }
+
+    /// Puts walls at the given locations, skipping snakes and replacing covered food.
+    fn add_walls(&mut self, locations: Vec<(usize, usize)>) -> Result<(), WallError> {
+        let (hx, hy) = self.snake_head();
+        if locations
+            .iter()
+            .any(|&(x, y)| (x as isize, y as isize) == (hx, hy))
+        {
+            return Err(WallError::OverlapsHead);
+        }
+
+        let food = self.food().len();
+        for (x, y) in locations {
+            self.set_tile(x, y, map::Tile::Wall);
+        }
+
+        for _ in self.food().len()..food {
+            self.create_food();
+        }
+
+        Ok(())
+    }
+}
+
+/// The reasons a wall pattern can't be added to the map.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum WallError {
+    /// The pattern would cover the snake's head.
+    OverlapsHead,
+    /// The given inset leaves no room for the pattern.
+    Inset(usize),
+}
+
+impl fmt::Display for WallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WallError::OverlapsHead => write!(f, "walls would cover the snake's head"),
+            WallError::Inset(inset) => write!(f, "a wall inset of {inset} doesn't fit on the map"),
+        }
+    }
+}
+
+impl std::error::Error for WallError {}